@@ -4,5 +4,5 @@
 echo 'setting up AoC aliases'
 alias p1='cargo run --bin part1'
 alias p2='cargo run --bin part2'
-alias t1='cargo test --bin part1'
-alias t2='cargo test --bin part2'
+alias t1='cargo test --lib part1'
+alias t2='cargo test --lib part2'
@@ -0,0 +1,217 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Retrieves the puzzle input of a day for a session, e.g: from the Advent
+/// of Code server
+pub trait Fetcher {
+    fn fetch(&self, session: &str, day: u8) -> io::Result<String>;
+}
+
+/// Local stand-in for the Advent of Code server: serves the inputs from a
+/// directory with the same paths as the server (`2022/day/<day>/input`),
+/// with one subfolder per session
+#[derive(Debug, Clone)]
+pub struct DirectoryFetcher {
+    root: PathBuf,
+}
+
+impl DirectoryFetcher {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, session: &str, day: u8) -> PathBuf {
+        self.root
+            .join(session)
+            .join("2022")
+            .join("day")
+            .join(day.to_string())
+            .join("input")
+    }
+}
+
+impl Fetcher for DirectoryFetcher {
+    fn fetch(&self, session: &str, day: u8) -> io::Result<String> {
+        let path = self.path(session, day);
+        fs::read_to_string(&path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+    }
+}
+
+/// Puzzle inputs of a session, stored in `<dir>/<session>/dayXX.txt`. Inputs
+/// that are not cached yet are retrieved with the `fetcher` (if any) and
+/// stored, so that they are never fetched again
+pub struct InputCache {
+    dir: PathBuf,
+    session: String,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>, session: impl Into<String>) -> io::Result<Self> {
+        let session = session.into();
+        // The session is used as a directory name, so it can't be a path
+        let valid = !session.is_empty()
+            && session
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid session name `{session}`"),
+            ));
+        }
+        Ok(Self {
+            dir: dir.into(),
+            session,
+            fetcher: None,
+        })
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    /// Path where the input of `day` is cached
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir
+            .join(&self.session)
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// Return the input of `day`, fetching and caching it if needed
+    pub fn get(&self, day: u8) -> io::Result<String> {
+        let path = self.path(day);
+        if path.exists() {
+            return fs::read_to_string(&path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())));
+        }
+        let Some(fetcher) = &self.fetcher else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "the input of day {day} for session `{}` is not cached and there is no fetcher",
+                    self.session
+                ),
+            ));
+        };
+        let input = fetcher.fetch(&self.session, day)?;
+        write_new(&path, &input)?;
+        Ok(input)
+    }
+}
+
+/// Write `contents` to a new file at `path`, creating its parent directories
+fn write_new(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Command line arguments used to select the input cache of a session
+#[derive(Debug, Clone, clap::Args)]
+pub struct CacheArgs {
+    /// Session whose cached inputs are used instead of `dayXX/input.txt`
+    #[arg(long, env = "AOC_SESSION")]
+    pub session: Option<String>,
+    /// Directory where the inputs of each session are cached
+    #[arg(long, env = "AOC_CACHE_DIR", default_value = ".aoc-cache")]
+    pub cache_dir: PathBuf,
+    /// Directory that stands in for the Advent of Code server to fetch the
+    /// inputs that are not cached yet
+    #[arg(long, env = "AOC_FETCH_DIR")]
+    pub fetch_dir: Option<PathBuf>,
+}
+
+impl CacheArgs {
+    /// Build the cache of the selected session (if any)
+    pub fn cache(&self) -> io::Result<Option<InputCache>> {
+        let Some(session) = &self.session else {
+            return Ok(None);
+        };
+        let mut cache = InputCache::new(&self.cache_dir, session)?;
+        if let Some(fetch_dir) = &self.fetch_dir {
+            cache = cache.with_fetcher(DirectoryFetcher::new(fetch_dir));
+        }
+        Ok(Some(cache))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+
+    /// Fetcher that counts how many times it has been called
+    struct CountingFetcher(Rc<Cell<usize>>);
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, session: &str, day: u8) -> io::Result<String> {
+            self.0.set(self.0.get() + 1);
+            Ok(format!("{session} {day}\n"))
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetches_only_once() {
+        let dir = temp_dir("once");
+        let count = Rc::new(Cell::new(0));
+        let cache = InputCache::new(&dir, "alice")
+            .unwrap()
+            .with_fetcher(CountingFetcher(count.clone()));
+
+        assert_eq!(cache.get(7).unwrap(), "alice 7\n");
+        assert_eq!(cache.get(7).unwrap(), "alice 7\n");
+        assert_eq!(count.get(), 1);
+        assert!(dir.join("alice").join("day07.txt").exists());
+
+        // Another session has its own inputs
+        let cache = InputCache::new(&dir, "bob")
+            .unwrap()
+            .with_fetcher(CountingFetcher(count.clone()));
+        assert_eq!(cache.get(7).unwrap(), "bob 7\n");
+        assert_eq!(count.get(), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_directory_fetcher() {
+        let server = temp_dir("server");
+        let cache_dir = temp_dir("local");
+        write_new(&server.join("alice/2022/day/3/input"), "vJrwpWtwJgWr\n").unwrap();
+        let cache = InputCache::new(&cache_dir, "alice")
+            .unwrap()
+            .with_fetcher(DirectoryFetcher::new(&server));
+
+        assert_eq!(cache.get(3).unwrap(), "vJrwpWtwJgWr\n");
+        // Once cached, the server is not needed anymore
+        fs::remove_dir_all(&server).unwrap();
+        assert_eq!(cache.get(3).unwrap(), "vJrwpWtwJgWr\n");
+        assert_eq!(cache.get(4).unwrap_err().kind(), io::ErrorKind::NotFound);
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_without_fetcher() {
+        let cache = InputCache::new(temp_dir("missing"), "alice").unwrap();
+        assert_eq!(cache.get(1).unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_invalid_session() {
+        assert!(InputCache::new("cache", "../alice").is_err());
+        assert!(InputCache::new("cache", "").is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where the raw contents of a puzzle input come from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    /// An input baked into the binary (e.g: with `include_str!`)
+    Embedded(&'static str),
+}
+
+impl Source {
+    /// Read the full contents of the input. I/O errors are reported with the
+    /// path that caused them instead of being dropped
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::File(path) => fs::read_to_string(path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| io::Error::new(e.kind(), format!("<stdin>: {e}")))?;
+                Ok(input)
+            }
+            Source::Embedded(input) => Ok(input.to_string()),
+        }
+    }
+
+    /// Open the input to read it line by line instead of all at once
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::File(path) => fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::Embedded(input) => Ok(Box::new(input.as_bytes())),
+        }
+    }
+}
+
+/// Command line arguments used to select the puzzle input of a day
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct InputArgs {
+    /// Path to the puzzle input, or `-` to read it from stdin
+    #[arg(short, long, conflicts_with = "sample")]
+    pub input: Option<String>,
+    /// Use the `N`th sample input (`sample.txt` for 1, `sampleN.txt` otherwise)
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "1",
+        value_parser = clap::value_parser!(u8).range(1..)
+    )]
+    pub sample: Option<u8>,
+}
+
+impl InputArgs {
+    /// Returns `true` if no input has been selected explicitly
+    pub fn is_default(&self) -> bool {
+        self.input.is_none() && self.sample.is_none()
+    }
+
+    /// Resolve the selected input, where `dir` is the directory of the day
+    /// that holds its `input.txt` and samples
+    pub fn source(&self, dir: &Path) -> Source {
+        match (&self.input, self.sample) {
+            (Some(path), _) if path == "-" => Source::Stdin,
+            (Some(path), _) => Source::File(path.into()),
+            (None, Some(1)) => Source::File(dir.join("sample.txt")),
+            (None, Some(n)) => Source::File(dir.join(format!("sample{n}.txt"))),
+            (None, None) => Source::File(dir.join("input.txt")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file_reports_path() {
+        let error = Source::File("does/not/exist.txt".into())
+            .read()
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().starts_with("does/not/exist.txt: "));
+    }
+
+    #[test]
+    fn test_embedded() {
+        let source = Source::Embedded("1\n2\n");
+        assert_eq!(source.read().unwrap(), "1\n2\n");
+        let lines: Vec<_> = source
+            .reader()
+            .unwrap()
+            .lines()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(lines, ["1", "2"]);
+    }
+
+    #[test]
+    fn test_input_args_source() {
+        let dir = Path::new("day06");
+        let args = |input: Option<&str>, sample| InputArgs {
+            input: input.map(String::from),
+            sample,
+        };
+        assert_eq!(
+            args(None, None).source(dir),
+            Source::File("day06/input.txt".into())
+        );
+        assert_eq!(
+            args(None, Some(1)).source(dir),
+            Source::File("day06/sample.txt".into())
+        );
+        assert_eq!(
+            args(None, Some(9)).source(dir),
+            Source::File("day06/sample9.txt".into())
+        );
+        assert_eq!(
+            args(Some("other.txt"), None).source(dir),
+            Source::File("other.txt".into())
+        );
+        assert_eq!(args(Some("-"), None).source(dir), Source::Stdin);
+    }
+}
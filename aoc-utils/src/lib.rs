@@ -0,0 +1,99 @@
+use clap::Parser;
+use input::InputArgs;
+use parse::ParseError;
+use std::{fmt::Display, io, path::Path, process::ExitCode};
+
+pub mod cache;
+pub mod input;
+pub mod parse;
+
+/// One of the two parts of a day's puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("invalid part {value}")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Common interface of every day's solution, so that the same input can be
+/// parsed once and solved for both parts by the runner, benchmarks or tests
+pub trait Solution {
+    /// Parsed representation of the input, shared by both parts
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parse the raw contents of the puzzle input
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// Solve the given `part` for an already parsed input, rendering the
+    /// answer as a string
+    fn solve(input: &Self::Input, part: Part) -> String {
+        match part {
+            Part::One => Self::part1(input).to_string(),
+            Part::Two => Self::part2(input).to_string(),
+        }
+    }
+}
+
+/// Solve one part of the puzzle of a day
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Entry point of the `part1`/`part2` binaries of each day: solve `part`
+/// for the input selected in the command line (`input.txt` of the current
+/// directory by default) and print the answer
+pub fn main<S: Solution>(part: Part) -> ExitCode {
+    exit(solve::<S>(part))
+}
+
+fn solve<S: Solution>(part: Part) -> io::Result<()> {
+    let args = Args::parse();
+    let input = args.input.source(Path::new(".")).read()?;
+    let input = S::parse(&input)?;
+    println!("{}", S::solve(&input, part));
+    Ok(())
+}
+
+/// Exit code for the `result` of a binary, printing the error (if any) in a
+/// readable form
+pub fn exit(result: io::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
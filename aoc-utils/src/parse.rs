@@ -0,0 +1,350 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    str::{FromStr, SplitWhitespace},
+};
+use thiserror::Error;
+
+/// What went wrong while parsing a puzzle input
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ErrorKind {
+    /// Something else was found where this was expected
+    #[error("expected {0}")]
+    Expected(String),
+    #[error("invalid number ({0})")]
+    InvalidNumber(String),
+    /// Well formed text that does not make sense in its context
+    #[error("{0}")]
+    Invalid(String),
+}
+
+/// Error found while parsing a puzzle input, with the position and the text
+/// that caused it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, starting at 1
+    pub line: usize,
+    /// Column of the line where the offending text starts, starting at 1
+    pub column: usize,
+    /// Offending text, empty if something is missing at the end of the line
+    pub text: String,
+    /// Full contents of the line, to show the error in context
+    pub source_line: String,
+    pub kind: ErrorKind,
+}
+
+impl std::error::Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )?;
+        if let ErrorKind::Expected(_) = self.kind {
+            if self.text.is_empty() {
+                write!(f, ", found the end of the line")?;
+            } else {
+                write!(f, ", found `{}`", self.text)?;
+            }
+        }
+        // Point at the offending text below the line
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column - 1);
+        let marker = "^".repeat(self.text.chars().count().max(1));
+        write!(
+            f,
+            "\n{gutter} |\n{} | {}\n{gutter} | {padding}{marker}",
+            self.line, self.source_line
+        )
+    }
+}
+
+impl From<ParseError> for io::Error {
+    fn from(error: ParseError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
+
+/// A line of the input that knows its position, to report errors in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Line number, starting at 1
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// Build an error for the text `at`, which should be a slice of this
+    /// line. Anything else is reported at the end of the line
+    pub fn error(&self, at: &str, kind: ErrorKind) -> ParseError {
+        let base = self.text.as_ptr() as usize;
+        let start = at.as_ptr() as usize;
+        let offset = if start >= base && start + at.len() <= base + self.text.len() {
+            start - base
+        } else {
+            self.text.len()
+        };
+        ParseError {
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            text: at.to_string(),
+            source_line: self.text.to_string(),
+            kind,
+        }
+    }
+
+    pub fn expected(&self, at: &str, what: impl Into<String>) -> ParseError {
+        self.error(at, ErrorKind::Expected(what.into()))
+    }
+
+    pub fn invalid(&self, at: &str, message: impl Into<String>) -> ParseError {
+        self.error(at, ErrorKind::Invalid(message.into()))
+    }
+
+    /// Empty slice at the end of the line, to report missing text
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    /// Parse the text `at` (a slice of this line) as a number
+    pub fn number<T>(&self, at: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        at.parse()
+            .map_err(|e: T::Err| self.error(at, ErrorKind::InvalidNumber(e.to_string())))
+    }
+
+    /// Every integer found in the line, ignoring whatever surrounds them. A
+    /// `-` is only taken as a sign when it does not follow a digit or a
+    /// letter, so ranges like `2-4` yield `[2, 4]`
+    pub fn numbers<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let text = self.text;
+        let mut numbers = vec![];
+        let mut start = None;
+        for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+            match (start, c) {
+                (None, '0'..='9') => {
+                    let before = &text[..i];
+                    let negative = before.ends_with('-')
+                        && !before[..i - 1].ends_with(|c: char| c.is_ascii_alphanumeric());
+                    start = Some(if negative { i - 1 } else { i });
+                }
+                (Some(begin), c) if !c.is_ascii_digit() => {
+                    numbers.push(self.number(&text[begin..i])?);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        Ok(numbers)
+    }
+
+    /// Return what follows `prefix` in the line (ignoring the indentation)
+    pub fn after(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        let text = self.text.trim_start();
+        text.strip_prefix(prefix)
+            .ok_or_else(|| self.expected(text, format!("`{prefix}`")))
+    }
+
+    /// Iterate over the whitespace separated words of the line
+    pub fn words(&self) -> Words<'a> {
+        Words {
+            line: *self,
+            words: self.text.split_whitespace(),
+        }
+    }
+}
+
+/// Whitespace separated words of a `Line`
+#[derive(Debug, Clone)]
+pub struct Words<'a> {
+    line: Line<'a>,
+    words: SplitWhitespace<'a>,
+}
+
+impl<'a> Words<'a> {
+    /// Next word, which is described as `what` if it is missing
+    pub fn word(&mut self, what: &str) -> Result<&'a str, ParseError> {
+        self.words
+            .next()
+            .ok_or_else(|| self.line.expected(self.line.end(), what))
+    }
+
+    /// Next word parsed as a number, which is described as `what` if it is
+    /// missing
+    pub fn number<T>(&mut self, what: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let word = self.word(what)?;
+        self.line.number(word)
+    }
+
+    /// Check that the next word is exactly `keyword`
+    pub fn keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
+        let what = format!("`{keyword}`");
+        let word = self.word(&what)?;
+        if word != keyword {
+            return Err(self.line.expected(word, what));
+        }
+        Ok(())
+    }
+
+    /// Check that there are no words left
+    pub fn finish(mut self) -> Result<(), ParseError> {
+        match self.words.next() {
+            Some(word) => Err(self.line.expected(word, "the end of the line")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.words.next()
+    }
+}
+
+/// Numbered lines of the input
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// Numbered lines of the input, in groups separated by blank lines
+pub fn groups(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut groups = vec![];
+    let mut group = vec![];
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(std::mem::take(&mut group));
+            }
+        } else {
+            group.push(line);
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    groups
+}
+
+/// Parse the non-blank lines of the input as a grid, one row per line,
+/// which must all have the same length. `cell` parses each character, given
+/// as a slice of its line to report errors at it, with its column and row
+/// (from 0)
+pub fn grid<'a, T>(
+    input: &'a str,
+    mut cell: impl FnMut(&Line<'a>, &'a str, (usize, usize)) -> Result<T, ParseError>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut grid: Vec<Vec<T>> = vec![];
+    for line in lines(input).filter(|line| !line.text.trim().is_empty()) {
+        let text = line.text.trim();
+        let y = grid.len();
+        let row = text
+            .char_indices()
+            .enumerate()
+            .map(|(x, (i, c))| cell(&line, &text[i..i + c.len_utf8()], (x, y)))
+            .collect::<Result<Vec<_>, _>>()?;
+        // The grid is a rectangle
+        if let Some(first) = grid.first() {
+            if row.len() != first.len() {
+                let message = format!("expected {} cells, found {}", first.len(), row.len());
+                return Err(line.invalid(text, message));
+            }
+        }
+        grid.push(row);
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let line = Line::new(3, "move 1 from x to 2");
+        let mut words = line.words();
+        words.keyword("move").unwrap();
+        assert_eq!(words.number::<usize>("a count"), Ok(1));
+        words.keyword("from").unwrap();
+        let err = words.number::<usize>("a stack").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 13));
+        assert_eq!(err.text, "x");
+        assert!(matches!(err.kind, ErrorKind::InvalidNumber(_)));
+
+        let err = line.expected(line.end(), "a number");
+        assert_eq!(err.column, 19);
+        assert_eq!(err.text, "");
+    }
+
+    #[test]
+    fn test_display() {
+        let line = Line::new(12, "A W");
+        let err = line.expected(&line.text[2..], "`X`, `Y` or `Z`");
+        assert_eq!(
+            err.to_string(),
+            "line 12, column 3: expected `X`, `Y` or `Z`, found `W`\n   |\n12 | A W\n   |   ^"
+        );
+    }
+
+    #[test]
+    fn test_words() {
+        let line = Line::new(1, "  addx -3 4");
+        let mut words = line.words();
+        assert_eq!(words.keyword("noop").unwrap_err().column, 3);
+        assert_eq!(words.number::<i64>("a value"), Ok(-3));
+        assert_eq!(words.clone().finish().unwrap_err().text, "4");
+        assert_eq!(words.word("a value"), Ok("4"));
+        assert_eq!(words.word("a value").unwrap_err().column, 12);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let line = Line::new(1, "move 12 from -3 to 4-5");
+        assert_eq!(line.numbers::<i64>(), Ok(vec![12, -3, 4, 5]));
+        let line = Line::new(1, "Starting items: 79, 98");
+        assert_eq!(line.numbers::<u64>(), Ok(vec![79, 98]));
+        let err = Line::new(1, "x=-1").numbers::<u64>().unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (3, "-1"));
+    }
+
+    #[test]
+    fn test_grid() {
+        let digit = |line: &Line, cell: &str, _| line.number::<u32>(cell);
+        assert_eq!(grid("12\n\n34\n", digit), Ok(vec![vec![1, 2], vec![3, 4]]));
+        let positions = grid("ab\ncd", |_, _, position| Ok(position)).unwrap();
+        assert_eq!(positions[1], vec![(0, 1), (1, 1)]);
+
+        let err = grid("12\n3x\n", digit).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = grid("12\n345\n", digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "345"));
+    }
+
+    #[test]
+    fn test_groups() {
+        let groups = groups("1\n2\n\n\n3\n");
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1], vec![Line::new(5, "3")]);
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day00::Day00;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day00>(Part::One)
+}
@@ -0,0 +1,29 @@
+use aoc_utils::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse::lines(input)
+            .map(|line| line.text.to_string())
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
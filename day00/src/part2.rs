@@ -1,46 +1,20 @@
-use std::{
-    fs::File,
-    io::{self, BufRead},
-};
-
-fn main() -> io::Result<()> {
-    let input = lines("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn lines(path: &str) -> io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-    Ok(lines.into_iter().flatten().collect())
-}
-
-fn _line_words(path: &str) -> io::Result<Vec<Vec<String>>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-
-    let mut parsed = Vec::new();
-    for line in lines.into_iter().flatten() {
-        let words: Vec<String> = line.split_whitespace().map(String::from).collect();
-        parsed.push(words);
-    }
-
-    Ok(parsed)
-}
-
-fn solve(_input: Vec<String>) -> u64 {
+pub fn solve(_input: &[String]) -> u64 {
+    // TODO: solve the puzzle, the test fails until then
     1
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day00;
+    use aoc_utils::Solution;
 
     #[test]
+    #[ignore = "placeholder of the template"]
     fn test_sample() {
-        let input = lines("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day00::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
+        // TODO: replace with the expected answer of the sample
         assert_eq!(solution, 0)
     }
 }
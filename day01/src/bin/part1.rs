@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day01::Day01;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day01>(Part::One)
+}
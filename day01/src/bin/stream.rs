@@ -0,0 +1,27 @@
+use aoc_utils::input::InputArgs;
+use clap::Parser;
+use std::{io, path::Path, process::ExitCode};
+
+/// Solve both parts reading the inventory as it arrives, for inputs too
+/// large to load in memory
+#[derive(Debug, Parser)]
+struct Args {
+    /// Number of elves whose calories are added up for part 2
+    #[arg(short, default_value = "3")]
+    n: usize,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> ExitCode {
+    aoc_utils::exit(run(Args::parse()))
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let reader = args.input.source(Path::new(".")).reader()?;
+    let top = day01::stream::top(reader, args.n)?;
+    let part1 = top.first().map_or(0, |&(_, total)| total);
+    let part2: u64 = top.iter().map(|&(_, total)| total).sum();
+    println!("{part1}\n{part2}");
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Total calories carried by each elf, to ask different questions of the
+/// same input. Elves are identified by their index in the input
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    totals: Vec<u64>,
+}
+
+impl Inventory {
+    pub fn new(totals: Vec<u64>) -> Self {
+        Self { totals }
+    }
+
+    /// Number of elves
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// Total calories carried by the elf
+    pub fn total(&self, elf: usize) -> Option<u64> {
+        self.totals.get(elf).copied()
+    }
+
+    /// The `n` elves carrying the most calories with their totals, from the
+    /// most to the least (ties go to the first elf)
+    pub fn top(&self, n: usize) -> Vec<(usize, u64)> {
+        let mut top = Top::new(n);
+        for (elf, &total) in self.totals.iter().enumerate() {
+            top.push(elf, total);
+        }
+        top.into_vec()
+    }
+
+    /// Indices of the `n` elves carrying the most calories
+    pub fn top_elves(&self, n: usize) -> Vec<usize> {
+        self.top(n).into_iter().map(|(elf, _)| elf).collect()
+    }
+
+    /// Calories carried by the `n` elves carrying the most
+    pub fn top_sum(&self, n: usize) -> u64 {
+        self.top(n).into_iter().map(|(_, total)| total).sum()
+    }
+
+    /// Position of the elf when sorted by calories, starting at 1 for the
+    /// elf carrying the most. Elves with the same total share their rank
+    pub fn rank(&self, elf: usize) -> Option<usize> {
+        let total = self.total(elf)?;
+        Some(self.totals.iter().filter(|&&other| other > total).count() + 1)
+    }
+
+    /// The smallest total such that at least `p` percent of the elves carry
+    /// that much or less (nearest-rank method), for `p` in `0.0..=100.0`
+    pub fn percentile(&self, p: f64) -> Option<u64> {
+        if self.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let rank = (p / 100.0 * self.len() as f64).ceil() as usize;
+        Some(self.nth_smallest(rank.max(1) - 1))
+    }
+
+    /// Total in the middle of the elves, or the mean of the two in the middle
+    /// if there is an even number of them
+    pub fn median(&self) -> Option<f64> {
+        let len = self.len();
+        match len {
+            0 => None,
+            _ if len % 2 == 1 => Some(self.nth_smallest(len / 2) as f64),
+            _ => {
+                let low = self.nth_smallest(len / 2 - 1);
+                let high = self.nth_smallest(len / 2);
+                Some((low + high) as f64 / 2.0)
+            }
+        }
+    }
+
+    /// Total in position `n` (from 0) if they were sorted, without sorting
+    fn nth_smallest(&self, n: usize) -> u64 {
+        let mut totals = self.totals.clone();
+        *totals.select_nth_unstable(n).1
+    }
+}
+
+impl FromIterator<u64> for Inventory {
+    fn from_iter<T: IntoIterator<Item = u64>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Running top-N of the elves carrying the most calories. Only `n` elves are
+/// kept in memory at any time instead of sorting all of them
+#[derive(Debug, Clone)]
+pub struct Top {
+    n: usize,
+    /// Min-heap of the best elves so far, ties going to the first elf
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl Top {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: usize, total: u64) {
+        self.heap.push(Reverse((total, Reverse(elf))));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    /// Elves and their totals, from the most to the least calories
+    pub fn into_vec(self) -> Vec<(usize, u64)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(elf)))| (elf, total))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_utils::Solution;
+
+    fn sample() -> Inventory {
+        Day01::parse(include_str!("../sample.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_top() {
+        let inventory = sample();
+        assert_eq!(inventory.top(3), vec![(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(inventory.top_elves(1), vec![3]);
+        assert_eq!(inventory.top_sum(3), 45000);
+        assert_eq!(inventory.top(10).len(), 5);
+        assert!(inventory.top(0).is_empty());
+
+        // Ties go to the first elf
+        let inventory = Inventory::new(vec![5, 7, 7, 1]);
+        assert_eq!(inventory.top_elves(2), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_rank() {
+        let inventory = sample();
+        assert_eq!(inventory.rank(3), Some(1));
+        assert_eq!(inventory.rank(1), Some(5));
+        assert_eq!(inventory.rank(5), None);
+        assert_eq!(Inventory::new(vec![3, 9, 9]).rank(2), Some(1));
+    }
+
+    #[test]
+    fn test_statistics() {
+        let inventory = sample();
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(inventory.percentile(40.0), Some(6000));
+        assert_eq!(inventory.percentile(0.0), Some(4000));
+        assert_eq!(inventory.percentile(100.0), Some(24000));
+        assert_eq!(inventory.percentile(101.0), None);
+
+        assert_eq!(Inventory::new(vec![4, 1, 3, 2]).median(), Some(2.5));
+        assert_eq!(Inventory::default().median(), None);
+    }
+}
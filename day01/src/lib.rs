@@ -0,0 +1,59 @@
+use aoc_utils::{
+    parse::{self, ParseError},
+    Solution,
+};
+use inventory::Inventory;
+
+pub mod inventory;
+pub mod part1;
+pub mod part2;
+pub mod stream;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Total calories carried by each elf
+    type Input = Inventory;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::groups(input)
+            .into_iter()
+            .map(|group| {
+                group.into_iter().try_fold(0u64, |total, line| {
+                    let text = line.text.trim();
+                    let calories: u64 = line.number(text)?;
+                    total
+                        .checked_add(calories)
+                        .ok_or_else(|| line.invalid(text, "the total of the elf overflows"))
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(Inventory::new)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day01::parse("1000\n2000\n\n30OO\n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.text, "30OO");
+
+        let err = Day01::parse(&format!("1\n\n{}\n1\n", u64::MAX)).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert!(err.to_string().contains("the total of the elf overflows"));
+    }
+}
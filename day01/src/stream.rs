@@ -0,0 +1,79 @@
+use crate::inventory::Top;
+use aoc_utils::parse::Line;
+use std::io::{self, BufRead};
+
+/// The `n` elves carrying the most calories with their totals, reading the
+/// inventory line by line from `reader`. Only the current line and the
+/// running top are kept in memory, so the input can be arbitrarily large
+pub fn top<R: BufRead>(mut reader: R, n: usize) -> io::Result<Vec<(usize, u64)>> {
+    let mut top = Top::new(n);
+    let mut buf = String::new();
+    let mut number = 0;
+    let mut elf = 0;
+    // Total of the current elf, if any of its items has been read
+    let mut current: Option<u64> = None;
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+        number += 1;
+        let line = Line::new(number, buf.trim_end_matches(['\n', '\r']));
+        let text = line.text.trim();
+        if text.is_empty() {
+            if let Some(total) = current.take() {
+                top.push(elf, total);
+                elf += 1;
+            }
+        } else {
+            let calories: u64 = line.number(text)?;
+            let total = current.unwrap_or(0).checked_add(calories);
+            current =
+                Some(total.ok_or_else(|| line.invalid(text, "the total of the elf overflows"))?);
+        }
+    }
+    if let Some(total) = current {
+        top.push(elf, total);
+    }
+    Ok(top.into_vec())
+}
+
+/// Calories carried by the `n` elves carrying the most, reading the
+/// inventory from `reader`
+pub fn top_sum<R: BufRead>(reader: R, n: usize) -> io::Result<u64> {
+    Ok(top(reader, n)?.into_iter().map(|(_, total)| total).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_utils::Solution;
+
+    const SAMPLE: &str = include_str!("../sample.txt");
+
+    #[test]
+    fn test_top() {
+        let inventory = Day01::parse(SAMPLE).unwrap();
+        assert_eq!(top(SAMPLE.as_bytes(), 3).unwrap(), inventory.top(3));
+        assert_eq!(top_sum(SAMPLE.as_bytes(), 1).unwrap(), 24000);
+        assert_eq!(
+            top_sum("\n\n1\r\n2\r\n\r\n\r\n4\n".as_bytes(), 3).unwrap(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = top("1000\n2000\n\n30OO\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err
+            .to_string()
+            .starts_with("line 4, column 1: invalid number"));
+
+        let input = format!("1\n\n{}\n1\n", u64::MAX);
+        let err = top(input.as_bytes(), 1).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 4, column 1: "));
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day02::Day02;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day02>(Part::One)
+}
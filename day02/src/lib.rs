@@ -0,0 +1,111 @@
+use aoc_utils::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+pub mod rules;
+pub mod strategy;
+pub mod tournament;
+
+/// First column of the strategy guide: what the opponent is going to play
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opponent {
+    A,
+    B,
+    C,
+}
+
+impl Opponent {
+    /// Shape played in rock, paper, scissors
+    pub fn shape(self) -> rules::Shape {
+        match self {
+            Opponent::A => 0,
+            Opponent::B => 1,
+            Opponent::C => 2,
+        }
+    }
+}
+
+/// Second column of the strategy guide, whose meaning depends on the part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl Response {
+    /// Position of the letter, from 0
+    pub fn index(self) -> usize {
+        match self {
+            Response::X => 0,
+            Response::Y => 1,
+            Response::Z => 2,
+        }
+    }
+}
+
+/// A line of the strategy guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Opponent,
+    pub response: Response,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Round>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| {
+                let mut words = line.words();
+                let what = "`A`, `B` or `C`";
+                let opponent = match words.word(what)? {
+                    "A" => Opponent::A,
+                    "B" => Opponent::B,
+                    "C" => Opponent::C,
+                    word => return Err(line.expected(word, what)),
+                };
+                let what = "`X`, `Y` or `Z`";
+                let response = match words.word(what)? {
+                    "X" => Response::X,
+                    "Y" => Response::Y,
+                    "Z" => Response::Z,
+                    word => return Err(line.expected(word, what)),
+                };
+                words.finish()?;
+                Ok(Round { opponent, response })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day02::parse("A Y\nB W\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "W");
+
+        let err = Day02::parse("A Y\nB\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
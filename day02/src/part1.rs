@@ -1,109 +1,19 @@
-use std::{
-    fs::File,
-    io::{self, BufRead},
-};
+use crate::{rules::Rules, strategy::Interpretation, Round};
 
-#[derive(Debug, Clone, Copy)]
-enum Pick {
-    Rock,
-    Paper,
-    Scissor,
-}
-
-impl Pick {
-    fn score(&self) -> u64 {
-        match self {
-            Pick::Rock => 1,
-            Pick::Paper => 2,
-            Pick::Scissor => 3,
-        }
-    }
-}
-
-impl From<&str> for Pick {
-    fn from(pick_str: &str) -> Self {
-        match pick_str {
-            "A" | "X" => Pick::Rock,
-            "B" | "Y" => Pick::Paper,
-            "C" | "Z" => Pick::Scissor,
-            _ => panic!("invalid pick {}", pick_str),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Outcome {
-    Lose,
-    Draw,
-    Win,
-}
-
-impl Outcome {
-    fn score(&self) -> u64 {
-        match self {
-            Outcome::Lose => 0,
-            Outcome::Draw => 3,
-            Outcome::Win => 6,
-        }
-    }
-
-    fn from_game(pick: &Pick, other_pick: &Pick) -> Self {
-        match (pick, other_pick) {
-            (Pick::Rock, Pick::Paper) => Outcome::Lose,
-            (Pick::Rock, Pick::Scissor) => Outcome::Win,
-            (Pick::Paper, Pick::Rock) => Outcome::Win,
-            (Pick::Paper, Pick::Scissor) => Outcome::Lose,
-            (Pick::Scissor, Pick::Rock) => Outcome::Lose,
-            (Pick::Scissor, Pick::Paper) => Outcome::Win,
-            _ => Outcome::Draw,
-        }
-    }
-
-    fn score_from_game(pick: &Pick, other_pick: &Pick) -> u64 {
-        let outcome = Self::from_game(pick, other_pick);
-        outcome.score() + pick.score()
-    }
-}
-
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn parse(path: &str) -> io::Result<Vec<Vec<String>>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-
-    let mut parsed = Vec::new();
-    for line in lines.into_iter().flatten() {
-        let words: Vec<String> = line.split_whitespace().map(String::from).collect();
-        parsed.push(words);
-    }
-
-    Ok(parsed)
-}
-
-fn solve(input: Vec<Vec<String>>) -> u64 {
-    input
-        .into_iter()
-        .map(|line| {
-            let other = Pick::from(line[0].as_ref());
-            let pick = Pick::from(line[1].as_ref());
-            Outcome::score_from_game(&pick, &other)
-        })
-        .sum()
+pub fn solve(input: &[Round]) -> u64 {
+    Interpretation::Shapes([0, 1, 2]).score(&Rules::rock_paper_scissors(), input)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day02;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = parse("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day02::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 15)
     }
 }
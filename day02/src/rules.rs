@@ -0,0 +1,252 @@
+/// Result of a round for one of the players
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    /// Outcome for the other player
+    pub fn reverse(self) -> Self {
+        match self {
+            Outcome::Lose => Outcome::Win,
+            Outcome::Draw => Outcome::Draw,
+            Outcome::Win => Outcome::Lose,
+        }
+    }
+}
+
+/// Shape of the game, as its index in the `Rules`
+pub type Shape = usize;
+
+/// Rules of a rock-paper-scissors like game: which shape beats which, and
+/// the score of each shape and outcome for the player
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    /// `beats[a][b]` is whether shape `a` beats shape `b`
+    beats: Vec<Vec<bool>>,
+    shape_scores: Vec<u64>,
+    /// Score for losing, drawing and winning
+    outcome_scores: [u64; 3],
+}
+
+impl Rules {
+    /// Classic Rock, Paper, Scissors with the scores of the puzzle
+    pub fn rock_paper_scissors() -> Self {
+        Self::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    /// Rock, Paper, Scissors, Lizard, Spock
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::from_graph(
+            &["Rock", "Paper", "Scissors", "Lizard", "Spock"],
+            &[
+                (0, 2), // Rock crushes Scissors
+                (0, 3), // Rock crushes Lizard
+                (1, 0), // Paper covers Rock
+                (1, 4), // Paper disproves Spock
+                (2, 1), // Scissors cuts Paper
+                (2, 3), // Scissors decapitates Lizard
+                (3, 1), // Lizard eats Paper
+                (3, 4), // Lizard poisons Spock
+                (4, 0), // Spock vaporizes Rock
+                (4, 2), // Spock smashes Scissors
+            ],
+        )
+        .unwrap()
+    }
+
+    /// Balanced game where each shape beats the half of the shapes that come
+    /// before it (wrapping around), which needs an odd number of shapes
+    pub fn cyclic(names: &[&str]) -> Result<Self, String> {
+        let n = names.len();
+        if n.is_multiple_of(2) {
+            return Err(format!(
+                "a cyclic game needs an odd number of shapes, not {n}"
+            ));
+        }
+        let edges: Vec<_> = (0..n)
+            .flat_map(|a| (1..=n / 2).map(move |k| (a, (a + n - k) % n)))
+            .collect();
+        Self::from_graph(names, &edges)
+    }
+
+    /// Game where shape `a` beats shape `b` for every `(a, b)` edge. Every
+    /// two different shapes must have exactly one edge between them
+    pub fn from_graph(names: &[&str], edges: &[(Shape, Shape)]) -> Result<Self, String> {
+        let n = names.len();
+        let mut beats = vec![vec![false; n]; n];
+        for &(a, b) in edges {
+            if a >= n || b >= n {
+                return Err(format!("unknown shape in edge ({a}, {b})"));
+            }
+            if a == b {
+                return Err(format!("{} can not beat itself", names[a]));
+            }
+            beats[a][b] = true;
+        }
+        for a in 0..n {
+            for b in a + 1..n {
+                match (beats[a][b], beats[b][a]) {
+                    (true, true) => {
+                        return Err(format!("{} and {} beat each other", names[a], names[b]))
+                    }
+                    (false, false) => {
+                        return Err(format!(
+                            "{} and {} do not beat each other",
+                            names[a], names[b]
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(Self {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            beats,
+            shape_scores: (1..=n as u64).collect(),
+            outcome_scores: [0, 3, 6],
+        })
+    }
+
+    /// Replace the score of each shape, which defaults to its position
+    /// starting at 1
+    pub fn with_shape_scores(mut self, scores: &[u64]) -> Result<Self, String> {
+        if scores.len() != self.len() {
+            return Err(format!(
+                "expected {} shape scores, found {}",
+                self.len(),
+                scores.len()
+            ));
+        }
+        self.shape_scores = scores.to_vec();
+        Ok(self)
+    }
+
+    /// Replace the score of each outcome, which default to 0, 3 and 6
+    pub fn with_outcome_scores(mut self, lose: u64, draw: u64, win: u64) -> Self {
+        self.outcome_scores = [lose, draw, win];
+        self
+    }
+
+    /// Number of shapes
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        0..self.len()
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape]
+    }
+
+    /// Shape with the given name, ignoring case
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names
+            .iter()
+            .position(|other| other.eq_ignore_ascii_case(name))
+    }
+
+    /// Outcome of playing `shape` against `other`
+    pub fn outcome(&self, shape: Shape, other: Shape) -> Outcome {
+        if self.beats[shape][other] {
+            Outcome::Win
+        } else if self.beats[other][shape] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Shape to play against `other` to get the `outcome`. If several do,
+    /// the one with the highest score (and the first of those) is picked
+    pub fn shape_for(&self, other: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&shape| self.outcome(shape, other) == outcome)
+            .min_by_key(|&shape| std::cmp::Reverse(self.shape_scores[shape]))
+    }
+
+    pub fn shape_score(&self, shape: Shape) -> u64 {
+        self.shape_scores[shape]
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u64 {
+        self.outcome_scores[outcome as usize]
+    }
+
+    /// Score of playing `shape` against `other`
+    pub fn score(&self, shape: Shape, other: Shape) -> u64 {
+        self.shape_score(shape) + self.outcome_score(self.outcome(shape, other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let rules = Rules::rock_paper_scissors();
+        let [rock, paper, scissors] = [0, 1, 2];
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, scissors), Outcome::Win);
+        assert_eq!(rules.outcome(scissors, rock), Outcome::Lose);
+        assert_eq!(rules.outcome(paper, paper), Outcome::Draw);
+        assert_eq!(rules.score(paper, rock), 8);
+        assert_eq!(rules.shape_for(rock, Outcome::Lose), Some(scissors));
+        assert_eq!(rules.shape("scissors"), Some(scissors));
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let spock = rules.shape("Spock").unwrap();
+        let lizard = rules.shape("Lizard").unwrap();
+        assert_eq!(rules.outcome(lizard, spock), Outcome::Win);
+        // Both Paper and Lizard beat Spock, Lizard scores more
+        assert_eq!(rules.shape_for(spock, Outcome::Win), Some(lizard));
+
+        // Every shape beats exactly half of the others
+        let cyclic = Rules::cyclic(&["a", "b", "c", "d", "e"]).unwrap();
+        for rules in [rules, cyclic] {
+            for shape in rules.shapes() {
+                let wins = rules
+                    .shapes()
+                    .filter(|&other| rules.outcome(shape, other) == Outcome::Win)
+                    .count();
+                assert_eq!(wins, 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!(Rules::cyclic(&["a", "b"]).is_err());
+        assert!(Rules::from_graph(&["a", "b", "c"], &[(0, 1), (1, 2)]).is_err());
+        assert!(Rules::from_graph(&["a", "b"], &[(0, 1), (1, 0)]).is_err());
+        assert!(Rules::from_graph(&["a", "b"], &[(0, 2)]).is_err());
+        assert!(Rules::rock_paper_scissors()
+            .with_shape_scores(&[1, 2])
+            .is_err());
+    }
+
+    #[test]
+    fn test_scores() {
+        let rules = Rules::rock_paper_scissors()
+            .with_shape_scores(&[10, 20, 30])
+            .unwrap()
+            .with_outcome_scores(1, 2, 3);
+        assert_eq!(rules.score(0, 2), 13);
+        assert_eq!(rules.score(2, 2), 32);
+    }
+}
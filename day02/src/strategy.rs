@@ -0,0 +1,167 @@
+use crate::{
+    rules::{Outcome, Rules, Shape},
+    Response, Round,
+};
+
+/// Meaning of the second column of the strategy guide, with the value of
+/// `X`, `Y` and `Z` in that order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    /// The response is the shape to play
+    Shapes([Shape; 3]),
+    /// The response is how the round needs to end
+    Outcomes([Outcome; 3]),
+}
+
+impl Interpretation {
+    /// Parse a mapping such as `X=Rock Y=Paper Z=Scissors` or
+    /// `X=lose, Y=draw, Z=win`, using the shape names of the `rules`
+    pub fn parse(spec: &str, rules: &Rules) -> Result<Self, String> {
+        let mut shapes = [None; 3];
+        let mut outcomes = [None; 3];
+        for entry in spec.split([',', ' ']).filter(|entry| !entry.is_empty()) {
+            let (letter, value) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("expected `letter=value`, found `{entry}`"))?;
+            let response = match letter {
+                "X" => Response::X,
+                "Y" => Response::Y,
+                "Z" => Response::Z,
+                _ => return Err(format!("expected `X`, `Y` or `Z`, found `{letter}`")),
+            };
+            let i = response.index();
+            if shapes[i].is_some() || outcomes[i].is_some() {
+                return Err(format!("`{letter}` is mapped twice"));
+            }
+            match value.to_ascii_lowercase().as_str() {
+                "lose" => outcomes[i] = Some(Outcome::Lose),
+                "draw" => outcomes[i] = Some(Outcome::Draw),
+                "win" => outcomes[i] = Some(Outcome::Win),
+                _ => {
+                    let shape = rules
+                        .shape(value)
+                        .ok_or_else(|| format!("unknown shape or outcome `{value}`"))?;
+                    shapes[i] = Some(shape);
+                }
+            }
+        }
+        if let [Some(x), Some(y), Some(z)] = shapes {
+            Ok(Interpretation::Shapes([x, y, z]))
+        } else if let [Some(x), Some(y), Some(z)] = outcomes {
+            Ok(Interpretation::Outcomes([x, y, z]))
+        } else if shapes.iter().flatten().count() + outcomes.iter().flatten().count() == 3 {
+            Err("can not mix shapes and outcomes".to_string())
+        } else {
+            Err("`X`, `Y` and `Z` must all be mapped".to_string())
+        }
+    }
+
+    /// Every way of mapping the responses to three different shapes of the
+    /// `rules` (the 6 permutations for rock, paper, scissors)
+    pub fn shape_permutations(rules: &Rules) -> Vec<Self> {
+        let mut interpretations = vec![];
+        for x in rules.shapes() {
+            for y in rules.shapes().filter(|&y| y != x) {
+                for z in rules.shapes().filter(|&z| z != x && z != y) {
+                    interpretations.push(Interpretation::Shapes([x, y, z]));
+                }
+            }
+        }
+        interpretations
+    }
+
+    /// Shape to play in the `round` under these `rules`, if it is possible
+    pub fn shape(&self, rules: &Rules, round: &Round) -> Option<Shape> {
+        let i = round.response.index();
+        match self {
+            Interpretation::Shapes(shapes) => Some(shapes[i]),
+            Interpretation::Outcomes(outcomes) => {
+                rules.shape_for(round.opponent.shape(), outcomes[i])
+            }
+        }
+    }
+
+    /// Total score of following the `guide`. Rounds that can not end as
+    /// asked score nothing
+    pub fn score(&self, rules: &Rules, guide: &[Round]) -> u64 {
+        guide
+            .iter()
+            .filter_map(|round| {
+                let shape = self.shape(rules, round)?;
+                Some(rules.score(shape, round.opponent.shape()))
+            })
+            .sum()
+    }
+
+    /// Describe the mapping with the names of the `rules`, in the format
+    /// accepted by `parse`
+    pub fn describe(&self, rules: &Rules) -> String {
+        let values: Vec<String> = match self {
+            Interpretation::Shapes(shapes) => {
+                shapes.iter().map(|&s| rules.name(s).to_string()).collect()
+            }
+            Interpretation::Outcomes(outcomes) => outcomes
+                .iter()
+                .map(|outcome| format!("{outcome:?}").to_lowercase())
+                .collect(),
+        };
+        format!("X={} Y={} Z={}", values[0], values[1], values[2])
+    }
+}
+
+/// Score of the `guide` under every shape permutation, from the best to
+/// the worst (ties keep the order of `Interpretation::shape_permutations`)
+pub fn rank_permutations(rules: &Rules, guide: &[Round]) -> Vec<(Interpretation, u64)> {
+    let mut scores: Vec<_> = Interpretation::shape_permutations(rules)
+        .into_iter()
+        .map(|interpretation| (interpretation, interpretation.score(rules, guide)))
+        .collect();
+    scores.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_utils::Solution;
+
+    #[test]
+    fn test_parse() {
+        let rules = Rules::rock_paper_scissors();
+        assert_eq!(
+            Interpretation::parse("X=Rock Y=Paper Z=Scissors", &rules),
+            Ok(Interpretation::Shapes([0, 1, 2]))
+        );
+        assert_eq!(
+            Interpretation::parse("Z=win, X=lose, Y=draw", &rules),
+            Ok(Interpretation::Outcomes([
+                Outcome::Lose,
+                Outcome::Draw,
+                Outcome::Win
+            ]))
+        );
+        assert!(Interpretation::parse("X=Rock Y=Paper", &rules).is_err());
+        assert!(Interpretation::parse("X=Rock Y=Paper Z=win", &rules).is_err());
+        assert!(Interpretation::parse("X=Rock X=Paper Z=Rock", &rules).is_err());
+        assert!(Interpretation::parse("X=Rock Y=Paper Z=Lizard", &rules).is_err());
+        assert!(Interpretation::parse("W=Rock", &rules).is_err());
+
+        let interpretation = Interpretation::Shapes([2, 0, 1]);
+        let spec = interpretation.describe(&rules);
+        assert_eq!(spec, "X=Scissors Y=Rock Z=Paper");
+        assert_eq!(Interpretation::parse(&spec, &rules), Ok(interpretation));
+    }
+
+    #[test]
+    fn test_rank_permutations() {
+        let rules = Rules::rock_paper_scissors();
+        let guide = Day02::parse(include_str!("../sample.txt")).unwrap();
+        let ranking = rank_permutations(&rules, &guide);
+        assert_eq!(ranking.len(), 6);
+        assert!(ranking.contains(&(Interpretation::Shapes([0, 1, 2]), 15)));
+        // A Y, B X, C Z: play Paper, Scissors and Rock to win them all
+        assert_eq!(ranking[0], (Interpretation::Shapes([2, 1, 0]), 24));
+        assert!(ranking.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{
+    rules::{Outcome, Rules, Shape},
+    Round,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+/// A way of choosing the shape to play in each round
+pub trait Strategy {
+    /// Shape to play in the next round
+    fn play(&mut self, rules: &Rules) -> Shape;
+
+    /// Learn from the last round, where the opponent played `other`
+    fn observe(&mut self, _shape: Shape, _other: Shape) {}
+}
+
+/// Always plays the same shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fixed(pub Shape);
+
+impl Strategy for Fixed {
+    fn play(&mut self, _rules: &Rules) -> Shape {
+        self.0
+    }
+}
+
+/// Plays the given shapes in order, starting over after the last one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cyclic {
+    shapes: Vec<Shape>,
+    next: usize,
+}
+
+impl Cyclic {
+    /// Fails if there are no `shapes` to play
+    pub fn new(shapes: Vec<Shape>) -> Result<Self, String> {
+        if shapes.is_empty() {
+            return Err("a cyclic strategy needs some shapes".to_string());
+        }
+        Ok(Self { shapes, next: 0 })
+    }
+
+    /// Replays what the opponent does in the strategy `guide`, which must
+    /// have some rounds
+    pub fn from_guide(guide: &[Round]) -> Result<Self, String> {
+        Self::new(guide.iter().map(|round| round.opponent.shape()).collect())
+    }
+}
+
+impl Strategy for Cyclic {
+    fn play(&mut self, _rules: &Rules) -> Shape {
+        let shape = self.shapes[self.next];
+        self.next = (self.next + 1) % self.shapes.len();
+        shape
+    }
+}
+
+/// Shape most often seen in `counts` (the first of those on a tie)
+fn most_frequent(counts: impl Iterator<Item = (Shape, u64)>) -> Option<Shape> {
+    counts
+        .max_by_key(|&(shape, count)| (count, std::cmp::Reverse(shape)))
+        .map(|(shape, _)| shape)
+}
+
+/// Shape that beats the `predicted` one, or the first shape if nothing is
+/// predicted yet
+fn counter(rules: &Rules, predicted: Option<Shape>) -> Shape {
+    predicted
+        .and_then(|predicted| rules.shape_for(predicted, Outcome::Win))
+        .unwrap_or(0)
+}
+
+/// Plays against the shape the opponent has played the most so far
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrequencyCounter {
+    counts: BTreeMap<Shape, u64>,
+}
+
+impl Strategy for FrequencyCounter {
+    fn play(&mut self, rules: &Rules) -> Shape {
+        let predicted = most_frequent(self.counts.iter().map(|(&s, &c)| (s, c)));
+        counter(rules, predicted)
+    }
+
+    fn observe(&mut self, _shape: Shape, other: Shape) {
+        *self.counts.entry(other).or_default() += 1;
+    }
+}
+
+/// Predicts the next shape of the opponent from the one it played last,
+/// using how often each shape has followed each other one. Falls back to
+/// the most frequent shape when the last one has never been followed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markov {
+    transitions: BTreeMap<(Shape, Shape), u64>,
+    counts: BTreeMap<Shape, u64>,
+    last: Option<Shape>,
+}
+
+impl Markov {
+    /// Trained on what the opponent does in the strategy `guide`
+    pub fn from_guide(guide: &[Round]) -> Self {
+        let mut markov = Self::default();
+        let shapes: Vec<_> = guide.iter().map(|round| round.opponent.shape()).collect();
+        markov.train(&shapes);
+        markov
+    }
+
+    /// Learn from a sequence of shapes played by the opponent. It does not
+    /// change the last shape seen in the current game
+    pub fn train(&mut self, shapes: &[Shape]) {
+        for &shape in shapes {
+            *self.counts.entry(shape).or_default() += 1;
+        }
+        for pair in shapes.windows(2) {
+            *self.transitions.entry((pair[0], pair[1])).or_default() += 1;
+        }
+    }
+
+    /// Most likely next shape of the opponent
+    pub fn predict(&self) -> Option<Shape> {
+        let followers = self.last.and_then(|last| {
+            let range = (last, Shape::MIN)..=(last, Shape::MAX);
+            most_frequent(self.transitions.range(range).map(|(&(_, s), &c)| (s, c)))
+        });
+        followers.or_else(|| most_frequent(self.counts.iter().map(|(&s, &c)| (s, c))))
+    }
+}
+
+impl Strategy for Markov {
+    fn play(&mut self, rules: &Rules) -> Shape {
+        counter(rules, self.predict())
+    }
+
+    fn observe(&mut self, _shape: Shape, other: Shape) {
+        *self.counts.entry(other).or_default() += 1;
+        if let Some(last) = self.last {
+            *self.transitions.entry((last, other)).or_default() += 1;
+        }
+        self.last = Some(other);
+    }
+}
+
+/// Results of a game between two players
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Report {
+    pub rounds: u64,
+    pub wins: [u64; 2],
+    pub draws: u64,
+    pub scores: [u64; 2],
+}
+
+impl Report {
+    /// Fraction of the rounds won by the `player` (0 or 1)
+    pub fn win_rate(&self, player: usize) -> f64 {
+        self.wins[player] as f64 / self.rounds.max(1) as f64
+    }
+
+    /// Mean score per round of the `player` (0 or 1)
+    pub fn expected_score(&self, player: usize) -> f64 {
+        self.scores[player] as f64 / self.rounds.max(1) as f64
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} rounds, {} draws", self.rounds, self.draws)?;
+        for player in 0..2 {
+            writeln!(
+                f,
+                "player {}: {:.1}% wins, {:.2} points per round",
+                player + 1,
+                self.win_rate(player) * 100.0,
+                self.expected_score(player)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Play `rounds` rounds between `first` and `second`, letting both learn
+/// from each round
+pub fn simulate(
+    rules: &Rules,
+    first: &mut dyn Strategy,
+    second: &mut dyn Strategy,
+    rounds: u64,
+) -> Report {
+    let mut report = Report {
+        rounds,
+        ..Report::default()
+    };
+    for _ in 0..rounds {
+        let a = first.play(rules);
+        let b = second.play(rules);
+        match rules.outcome(a, b) {
+            Outcome::Win => report.wins[0] += 1,
+            Outcome::Lose => report.wins[1] += 1,
+            Outcome::Draw => report.draws += 1,
+        }
+        report.scores[0] += rules.score(a, b);
+        report.scores[1] += rules.score(b, a);
+        first.observe(a, b);
+        second.observe(b, a);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_utils::Solution;
+
+    const ROCK: Shape = 0;
+    const PAPER: Shape = 1;
+    const SCISSORS: Shape = 2;
+
+    #[test]
+    fn test_fixed_and_cyclic() {
+        let rules = Rules::rock_paper_scissors();
+        let mut cyclic = Cyclic::new(vec![ROCK, PAPER, SCISSORS]).unwrap();
+        let report = simulate(&rules, &mut Fixed(ROCK), &mut cyclic, 300);
+        assert_eq!(report.wins, [100, 100]);
+        assert_eq!(report.draws, 100);
+        // 1 point for Rock plus 3 for each draw and 6 for each win
+        assert_eq!(report.scores[0], 300 + 300 + 600);
+        assert!((report.win_rate(0) - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(report.expected_score(0), 4.0);
+
+        assert!(Cyclic::new(vec![]).is_err());
+        assert!(Cyclic::from_guide(&[]).is_err());
+    }
+
+    #[test]
+    fn test_frequency_counter() {
+        let rules = Rules::rock_paper_scissors();
+        let mut counter = FrequencyCounter::default();
+        let report = simulate(&rules, &mut counter, &mut Fixed(SCISSORS), 100);
+        assert_eq!(report.wins, [100, 0]);
+
+        // It only needs to see the opponent once
+        let mut counter = FrequencyCounter::default();
+        let report = simulate(&rules, &mut counter, &mut Fixed(PAPER), 100);
+        assert_eq!(report.wins, [99, 1]);
+    }
+
+    #[test]
+    fn test_markov() {
+        let rules = Rules::rock_paper_scissors();
+        let guide = Day02::parse(include_str!("../sample.txt")).unwrap();
+        let mut markov = Markov::from_guide(&guide);
+        let mut opponent = Cyclic::from_guide(&guide).unwrap();
+        let report = simulate(&rules, &mut markov, &mut opponent, 300);
+        assert!(report.win_rate(0) > 0.95, "{report}");
+
+        // The frequency counter can not follow the cycle
+        let mut counter = FrequencyCounter::default();
+        let mut opponent = Cyclic::from_guide(&guide).unwrap();
+        let report = simulate(&rules, &mut counter, &mut opponent, 300);
+        assert!(report.win_rate(0) < 0.5, "{report}");
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day03::Day03;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day03>(Part::Two)
+}
@@ -0,0 +1,99 @@
+use aoc_utils::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+pub mod reorganize;
+pub mod rucksack;
+
+use rucksack::{common, Items, Rucksack};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// Items of each rucksack
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<_> = parse::lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .collect();
+        let rucksacks = lines
+            .iter()
+            .map(|line| {
+                let items = line.text.trim();
+                if let Some(i) = items.find(|c: char| !c.is_ascii_alphabetic()) {
+                    return Err(line.expected(&items[i..i + 1], "an item (`a`-`z` or `A`-`Z`)"));
+                }
+                // Both compartments have the same number of items
+                if !items.len().is_multiple_of(2) {
+                    return Err(line.invalid(items, "odd number of items in the rucksack"));
+                }
+                // Exactly one item type is in both compartments
+                if let Ok(Err(e)) = Rucksack::new(items).map(|r| r.misplaced()) {
+                    return Err(line.invalid(items, e.to_string()));
+                }
+                Ok(items.to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Each group of 3 elves shares exactly one item type, their badge
+        for (group, lines) in rucksacks.chunks(3).zip(lines.chunks(3)) {
+            let last = &lines[lines.len() - 1];
+            if group.len() < 3 {
+                return Err(
+                    last.invalid(last.text.trim(), "the last group has less than 3 rucksacks")
+                );
+            }
+            let sets = group
+                .iter()
+                .map(|items| Items::try_from(items.as_str()).unwrap_or_default());
+            if let Err(e) = common(sets) {
+                return Err(last.invalid(last.text.trim(), format!("group without a badge: {e}")));
+            }
+        }
+        Ok(rucksacks)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day03::parse("vJrwpWtwJgWrhcsFMMfFFhFp\nabc-\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.text, "-");
+
+        let err = Day03::parse("abc\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.text, "abc");
+
+        let err = Day03::parse("abcd\nefgh\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert!(err.to_string().contains("no item in common"));
+
+        let err = Day03::parse("abca\ndefd\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(err.to_string().contains("less than 3 rucksacks"));
+
+        let err = Day03::parse("abca\ndefd\nghig\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert!(err
+            .to_string()
+            .contains("group without a badge: no item in common"));
+    }
+}
@@ -0,0 +1,153 @@
+use crate::rucksack::{halves, InvalidItem, Items, Rucksack};
+use std::fmt::{self, Display};
+
+/// Move of every copy of an item type from one compartment to the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub item: char,
+    /// Compartment the items are taken from (0 or 1)
+    pub from: usize,
+    pub count: usize,
+}
+
+impl Move {
+    /// Compartment the items are put in
+    pub fn to(&self) -> usize {
+        1 - self.from
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} {} from compartment {} to {}",
+            self.count,
+            self.item,
+            self.from + 1,
+            self.to() + 1
+        )
+    }
+}
+
+/// How to fix a rucksack so that no item type is in both compartments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reorganization {
+    pub moves: Vec<Move>,
+    /// Contents of each compartment after the moves
+    pub compartments: [String; 2],
+}
+
+impl Reorganization {
+    /// Number of items moved
+    pub fn len(&self) -> usize {
+        self.moves.iter().map(|m| m.count).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+}
+
+/// Plan the fewest item moves that leave no item type of the rucksack in
+/// both compartments. Each duplicated type moves out of the compartment
+/// with fewer copies of it (the second one on a tie), so the compartments
+/// may end up with different sizes. Moved items go after the others
+pub fn reorganize(items: &str) -> Result<Reorganization, InvalidItem> {
+    let (left, right) = halves(items);
+    let mut compartments = [left.to_string(), right.to_string()];
+    let rucksack = Rucksack::new(items)?;
+    let duplicated = rucksack.compartments[0].intersection(rucksack.compartments[1]);
+    let mut moves = vec![];
+    for item in duplicated.iter() {
+        let counts = compartments
+            .each_ref()
+            .map(|items| items.chars().filter(|&c| c == item).count());
+        let from = if counts[0] < counts[1] { 0 } else { 1 };
+        compartments[from].retain(|c| c != item);
+        compartments[1 - from].extend(std::iter::repeat_n(item, counts[from]));
+        moves.push(Move {
+            item,
+            from,
+            count: counts[from],
+        });
+    }
+    Ok(Reorganization {
+        moves,
+        compartments,
+    })
+}
+
+/// Check that the `compartments` hold the same items as the original
+/// rucksack and that no item type is in both of them
+pub fn validate(original: &str, compartments: &[String; 2]) -> Result<(), String> {
+    let mut before: Vec<char> = original.chars().collect();
+    let mut after: Vec<char> = compartments.concat().chars().collect();
+    before.sort_unstable();
+    after.sort_unstable();
+    if before != after {
+        return Err("the items are not the same as in the original rucksack".to_string());
+    }
+    let items = |c: &String| Items::try_from(c.as_str()).map_err(|e| e.to_string());
+    let (left, right) = (items(&compartments[0])?, items(&compartments[1])?);
+    let duplicated = left.intersection(right);
+    if !duplicated.is_empty() {
+        let items: String = duplicated.iter().collect();
+        return Err(format!("items in both compartments: {items}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use aoc_utils::Solution;
+
+    #[test]
+    fn test_reorganize() {
+        let plan = reorganize("aabBxbbB").unwrap();
+        assert_eq!(
+            plan.moves,
+            vec![
+                Move {
+                    item: 'b',
+                    from: 0,
+                    count: 1
+                },
+                Move {
+                    item: 'B',
+                    from: 1,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(plan.compartments, ["aaBB".to_string(), "xbbb".to_string()]);
+        assert_eq!(plan.len(), 2);
+        assert_eq!(
+            plan.moves[0].to_string(),
+            "move 1 b from compartment 1 to 2"
+        );
+
+        for rucksack in Day03::parse(include_str!("../sample.txt")).unwrap() {
+            let plan = reorganize(&rucksack).unwrap();
+            assert_eq!(plan.moves.len(), 1);
+            assert_eq!(validate(&rucksack, &plan.compartments), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let compartments = ["ab".to_string(), "cb".to_string()];
+        assert_eq!(
+            validate("abcb", &compartments),
+            Err("items in both compartments: b".to_string())
+        );
+        assert!(validate("abcd", &compartments).is_err());
+        assert!(reorganize("ab-b").is_err());
+        assert_eq!(
+            validate("abcb", &["abb".to_string(), "c".to_string()]),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt::{self, Display};
+
+/// Priority of an item: `a`-`z` are 1 to 26 and `A`-`Z` are 27 to 52
+pub fn priority(item: char) -> Option<u64> {
+    match item {
+        'a'..='z' => Some(item as u64 - 'a' as u64 + 1),
+        'A'..='Z' => Some(item as u64 - 'A' as u64 + 27),
+        _ => None,
+    }
+}
+
+fn item(priority: u64) -> char {
+    match priority {
+        1..=26 => (b'a' + priority as u8 - 1) as char,
+        _ => (b'A' + priority as u8 - 27) as char,
+    }
+}
+
+/// A character that is not an item type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidItem(pub char);
+
+impl Display for InvalidItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid item {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidItem {}
+
+/// Set of item types, as a mask with the bit `priority - 1` of each item
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Items(u64);
+
+impl Items {
+    pub const EMPTY: Items = Items(0);
+
+    /// Add an item, failing if it is not a letter
+    pub fn insert(&mut self, item: char) -> Result<(), InvalidItem> {
+        let priority = priority(item).ok_or(InvalidItem(item))?;
+        self.0 |= 1 << (priority - 1);
+        Ok(())
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).is_some_and(|priority| self.0 & (1 << (priority - 1)) != 0)
+    }
+
+    /// Number of item types
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    /// Items in this set that are not in `other`
+    pub fn difference(self, other: Items) -> Items {
+        Items(self.0 & !other.0)
+    }
+
+    /// Items from the lowest to the highest priority
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        let mask = self.0;
+        (1..=52)
+            .filter(move |p| mask & (1 << (p - 1)) != 0)
+            .map(item)
+    }
+
+    /// The only item of the set
+    pub fn single(&self) -> Result<char, CommonError> {
+        match self.len() {
+            0 => Err(CommonError::None),
+            1 => Ok(item(self.0.trailing_zeros() as u64 + 1)),
+            _ => Err(CommonError::Several(self.iter().collect())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Items {
+    type Error = InvalidItem;
+
+    fn try_from(items: &str) -> Result<Self, Self::Error> {
+        let mut set = Items::EMPTY;
+        for item in items.chars() {
+            set.insert(item)?;
+        }
+        Ok(set)
+    }
+}
+
+/// Why a group of item sets does not share exactly one item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommonError {
+    None,
+    Several(Vec<char>),
+}
+
+impl Display for CommonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommonError::None => write!(f, "no item in common"),
+            CommonError::Several(items) => {
+                let items: String = items.iter().collect();
+                write!(f, "several items in common ({items})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommonError {}
+
+/// Items found in every one of the `sets` (none if there are no sets)
+pub fn intersection(sets: impl IntoIterator<Item = Items>) -> Items {
+    let mut sets = sets.into_iter();
+    let first = sets.next().unwrap_or_default();
+    sets.fold(first, Items::intersection)
+}
+
+/// The only item found in every one of the `sets`
+pub fn common(sets: impl IntoIterator<Item = Items>) -> Result<char, CommonError> {
+    intersection(sets).single()
+}
+
+/// Split the `items` in two halves with the same number of characters (the
+/// second one gets the extra one if there is an odd number)
+pub(crate) fn halves(items: &str) -> (&str, &str) {
+    let half = items.chars().count() / 2;
+    let mid = items
+        .char_indices()
+        .nth(half)
+        .map_or(items.len(), |(i, _)| i);
+    items.split_at(mid)
+}
+
+/// Items of a rucksack, split in its two compartments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub compartments: [Items; 2],
+}
+
+impl Rucksack {
+    /// Each half of the `items` goes into a compartment
+    pub fn new(items: &str) -> Result<Self, InvalidItem> {
+        let (left, right) = halves(items);
+        Ok(Self {
+            compartments: [left.try_into()?, right.try_into()?],
+        })
+    }
+
+    /// Every item type in the rucksack
+    pub fn items(&self) -> Items {
+        self.compartments[0].union(self.compartments[1])
+    }
+
+    /// The item type found in both compartments
+    pub fn misplaced(&self) -> Result<char, CommonError> {
+        common(self.compartments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(items: &str) -> Items {
+        Items::try_from(items).unwrap()
+    }
+
+    #[test]
+    fn test_items() {
+        let items = self::items("abcAZ");
+        assert_eq!(items.len(), 5);
+        assert!(items.contains('Z') && !items.contains('z') && !items.contains('-'));
+        assert_eq!(items.iter().collect::<String>(), "abcAZ");
+
+        let other = self::items("cZz");
+        assert_eq!(items.union(other).iter().collect::<String>(), "abczAZ");
+        assert_eq!(items.difference(other).iter().collect::<String>(), "abA");
+        assert_eq!(
+            items.intersection(other).single(),
+            Err(CommonError::Several(vec!['c', 'Z']))
+        );
+        assert_eq!(priority('p'), Some(16));
+        assert_eq!(priority('L'), Some(38));
+
+        assert_eq!(Items::try_from("ab-c"), Err(InvalidItem('-')));
+        let mut set = Items::EMPTY;
+        assert_eq!(set.insert('é'), Err(InvalidItem('é')));
+    }
+
+    #[test]
+    fn test_common() {
+        let group = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        ];
+        assert_eq!(common(group.map(items)), Ok('r'));
+        assert_eq!(
+            common([items("abc"), items("bcd")]),
+            Err(CommonError::Several(vec!['b', 'c']))
+        );
+        assert_eq!(common([items("ab"), items("cd")]), Err(CommonError::None));
+        assert_eq!(common([]), Err(CommonError::None));
+
+        let rucksack = Rucksack::new(group[0]).unwrap();
+        assert_eq!(rucksack.misplaced(), Ok('p'));
+        assert_eq!(rucksack.items(), items(group[0]));
+    }
+
+    #[test]
+    fn test_invalid_rucksack() {
+        assert_eq!(halves("abé€"), ("ab", "é€"));
+        assert_eq!(Rucksack::new("aé"), Err(InvalidItem('é')));
+        assert_eq!(Rucksack::new("€a"), Err(InvalidItem('€')));
+    }
+}
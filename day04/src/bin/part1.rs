@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day04::Day04;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day04>(Part::One)
+}
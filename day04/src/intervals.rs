@@ -0,0 +1,229 @@
+use crate::{sweep::coverage, Section};
+use std::ops::RangeInclusive;
+
+/// Set of sections stored as sorted, disjoint and non-adjacent ranges
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    /// Inclusive bounds of each range
+    ranges: Vec<(Section, Section)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the sections of the `range`, merging it with the ones it
+    /// overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<Section>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Ranges before the new one that do not touch it
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.saturating_add(1) < start);
+        // Ranges from `first` on that touch it
+        let last =
+            first + self.ranges[first..].partition_point(|&(s, _)| s <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<Section>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of sections in the set, saturating at `u64::MAX`
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| end.abs_diff(start).saturating_add(1))
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Smallest range holding every section of the set
+    pub fn span(&self) -> Option<RangeInclusive<Section>> {
+        Some(self.ranges.first()?.0..=self.ranges.last()?.1)
+    }
+
+    pub fn contains(&self, section: Section) -> bool {
+        self.contains_range(&(section..=section))
+    }
+
+    /// Whether every section of the `range` is in the set
+    pub fn contains_range(&self, range: &RangeInclusive<Section>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let i = self
+            .ranges
+            .partition_point(|&(_, end)| end < *range.start());
+        self.ranges
+            .get(i)
+            .is_some_and(|&(start, end)| start <= *range.start() && *range.end() <= end)
+    }
+
+    /// Whether every section of `other` is in the set
+    pub fn is_superset(&self, other: &IntervalSet) -> bool {
+        other.ranges().all(|range| self.contains_range(&range))
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = self.ranges[i];
+            let (b_start, b_end) = other.ranges[j];
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Sections of the set that are not in `other`
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for &(start, end) in &self.ranges {
+            let mut start = Some(start);
+            // Skip the ranges of `other` that end before this one
+            while j < other.ranges.len() && other.ranges[j].1 < start.unwrap() {
+                j += 1;
+            }
+            let mut k = j;
+            while let Some(from) = start {
+                match other.ranges.get(k) {
+                    Some(&(o_start, o_end)) if o_start <= end => {
+                        if from < o_start {
+                            ranges.push((from, o_start - 1));
+                        }
+                        start = o_end.checked_add(1).filter(|&next| next <= end);
+                        k += 1;
+                    }
+                    _ => {
+                        ranges.push((from, end));
+                        start = None;
+                    }
+                }
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Sections of the `range` that are not in the set
+    pub fn uncovered(&self, range: RangeInclusive<Section>) -> IntervalSet {
+        IntervalSet::from(range).difference(self)
+    }
+}
+
+impl From<RangeInclusive<Section>> for IntervalSet {
+    fn from(range: RangeInclusive<Section>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<Section>> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = RangeInclusive<Section>>>(iter: T) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Sections assigned to at least two of the `assignments`
+pub fn covered_twice(assignments: &[RangeInclusive<Section>]) -> IntervalSet {
+    coverage(assignments)
+        .into_iter()
+        .filter(|&(_, depth)| depth >= 2)
+        .map(|(range, _)| range)
+        .collect()
+}
+
+/// Indices of the `assignments` whose sections are all assigned to someone
+/// else too
+pub fn redundant(assignments: &[RangeInclusive<Section>]) -> Vec<usize> {
+    let twice = covered_twice(assignments);
+    (0..assignments.len())
+        .filter(|&i| twice.contains_range(&assignments[i]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<Section>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut intervals = set(&[10..=12, 1..=3, 5..=6]);
+        assert_eq!(
+            intervals.ranges().collect::<Vec<_>>(),
+            vec![1..=3, 5..=6, 10..=12]
+        );
+        intervals.insert(4..=4);
+        intervals.insert(7..=9);
+        assert_eq!(intervals.ranges().collect::<Vec<_>>(), vec![1..=12]);
+        assert_eq!(intervals.len(), 12);
+        intervals.insert(0..=Section::MAX);
+        assert_eq!(intervals.span(), Some(0..=Section::MAX));
+        intervals.insert(Section::MIN..=-1);
+        assert_eq!(intervals.len(), u64::MAX);
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = set(&[1..=5, 10..=15]);
+        let b = set(&[4..=11, 15..=20]);
+        assert_eq!(a.union(&b), set(&[1..=20]));
+        assert_eq!(a.intersection(&b), set(&[4..=5, 10..=11, 15..=15]));
+        assert_eq!(a.difference(&b), set(&[1..=3, 12..=14]));
+        assert_eq!(b.difference(&a), set(&[6..=9, 16..=20]));
+        assert_eq!(a.difference(&set(&[0..=Section::MAX])), IntervalSet::new());
+        assert_eq!(a.uncovered(0..=16), set(&[0..=0, 6..=9, 16..=16]));
+
+        assert!(a.contains(12) && !a.contains(7));
+        assert!(a.contains_range(&(11..=15)) && !a.contains_range(&(4..=10)));
+        assert!(a.is_superset(&set(&[2..=3, 12..=13])));
+        assert!(!a.is_superset(&b));
+    }
+
+    #[test]
+    fn test_redundant() {
+        let assignments = [2..=4, 6..=8, 2..=3, 4..=5, 3..=7, 6..=6];
+        assert_eq!(covered_twice(&assignments), set(&[2..=7]));
+        assert_eq!(redundant(&assignments), vec![0, 2, 3, 4, 5]);
+        assert_eq!(redundant(&[1..=5, 2..=3, 4..=9]), vec![1]);
+        assert!(redundant(&[1..=1, 2..=2]).is_empty());
+    }
+}
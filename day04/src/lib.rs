@@ -0,0 +1,204 @@
+use aoc_utils::{
+    parse::{self, ErrorKind, Line, ParseError},
+    Solution,
+};
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+    ops::RangeInclusive,
+};
+
+pub mod intervals;
+pub mod part1;
+pub mod part2;
+pub mod sweep;
+
+/// Identifier of a section of the camp
+pub type Section = i64;
+
+/// Sections assigned to each elf of a group
+pub type Group = Vec<RangeInclusive<Section>>;
+
+/// Why a range of sections could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// There is nothing where a range was expected
+    Empty,
+    /// A bound that is not a number, and its byte offset in the range
+    InvalidBound {
+        bound: String,
+        offset: usize,
+        error: ParseIntError,
+    },
+    /// The range ends before it starts
+    Reversed { start: Section, end: Section },
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::Empty => write!(f, "expected a range like `2-4`"),
+            RangeError::InvalidBound { bound, error, .. } => {
+                write!(f, "invalid bound `{bound}` ({error})")
+            }
+            RangeError::Reversed { start, end } => {
+                write!(f, "the range ends ({end}) before it starts ({start})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// Parse a range of sections, which can be:
+///
+/// - `2-4`, `-3--1` or `-3-1`: both bounds, which may be negative
+/// - `6`: a single section
+/// - `5-`, `5-*` or `*-5`: open-ended, up to the last or from the first
+///   section
+pub fn parse_range(text: &str) -> Result<RangeInclusive<Section>, RangeError> {
+    if text.is_empty() {
+        return Err(RangeError::Empty);
+    }
+    // The separator is the first `-` that is not the sign of the start
+    let first = text.chars().next().map_or(0, char::len_utf8);
+    let (start, end) = match text[first..].find('-') {
+        Some(i) => (
+            &text[..first + i],
+            Some((&text[first + i + 1..], first + i + 1)),
+        ),
+        None => (text, None),
+    };
+    let bound = |bound: &str, offset: usize, open: Section| {
+        if bound == "*" || bound.is_empty() {
+            return Ok(open);
+        }
+        bound.parse().map_err(|error| RangeError::InvalidBound {
+            bound: bound.to_string(),
+            offset,
+            error,
+        })
+    };
+    let start = bound(start, 0, Section::MIN)?;
+    let end = match end {
+        Some((end, offset)) => bound(end, offset, Section::MAX)?,
+        None if text == "*" => Section::MAX,
+        None => start,
+    };
+    if start > end {
+        return Err(RangeError::Reversed { start, end });
+    }
+    Ok(start..=end)
+}
+
+/// Parse the range `text` (a slice of the `line`), reporting errors at it
+fn parse_range_at(line: &Line, text: &str) -> Result<RangeInclusive<Section>, ParseError> {
+    parse_range(text).map_err(|e| match e {
+        RangeError::Empty => line.expected(text, "a range like `2-4`"),
+        RangeError::InvalidBound {
+            ref bound,
+            offset,
+            ref error,
+        } => line.error(
+            &text[offset..offset + bound.len()],
+            ErrorKind::InvalidNumber(error.to_string()),
+        ),
+        RangeError::Reversed { .. } => line.invalid(text, e.to_string()),
+    })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Group>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| {
+                line.text
+                    .trim()
+                    .split(',')
+                    .map(|range| parse_range_at(&line, range.trim()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("2-4"), Ok(2..=4));
+        assert_eq!(parse_range("-3--1"), Ok(-3..=-1));
+        assert_eq!(parse_range("-3-1"), Ok(-3..=1));
+        assert_eq!(parse_range("6"), Ok(6..=6));
+        assert_eq!(parse_range("-6"), Ok(-6..=-6));
+        assert_eq!(parse_range("5-"), Ok(5..=Section::MAX));
+        assert_eq!(parse_range("5-*"), Ok(5..=Section::MAX));
+        assert_eq!(parse_range("*-5"), Ok(Section::MIN..=5));
+        assert_eq!(parse_range("*"), Ok(Section::MIN..=Section::MAX));
+
+        assert_eq!(parse_range(""), Err(RangeError::Empty));
+        assert_eq!(
+            parse_range("8-6"),
+            Err(RangeError::Reversed { start: 8, end: 6 })
+        );
+        assert!(matches!(
+            parse_range("2-x"),
+            Err(RangeError::InvalidBound { offset: 2, .. })
+        ));
+        assert!(matches!(
+            parse_range("€-3"),
+            Err(RangeError::InvalidBound { offset: 0, .. })
+        ));
+        assert!(matches!(
+            parse_range("2-€"),
+            Err(RangeError::InvalidBound { offset: 2, .. })
+        ));
+        assert!(matches!(
+            parse_range("--5"),
+            Err(RangeError::InvalidBound { offset: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = Day04::parse("2-4,6-8,-1\n5-\n").unwrap();
+        assert_eq!(
+            input,
+            vec![vec![2..=4, 6..=8, -1..=-1], vec![5..=Section::MAX]]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day04::parse("2-4,6-8\n2-3,4-x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.text, "x");
+
+        let err = Day04::parse("€-3,1-2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.text, "€");
+
+        let err = Day04::parse("2-4,\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+
+        let err = Day04::parse("2-4,8-6\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.text, "8-6");
+    }
+}
@@ -1,68 +1,25 @@
-use std::{
-    fs::File,
-    io::{self, BufRead},
-    ops::RangeInclusive,
-};
+use crate::{intervals::IntervalSet, Group};
 
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
+/// Whether an assignment of the group contains another one
+fn contains_another(group: &Group) -> bool {
+    let sets: Vec<IntervalSet> = group.iter().cloned().map(IntervalSet::from).collect();
+    (0..sets.len()).any(|i| (0..sets.len()).any(|j| i != j && sets[i].is_superset(&sets[j])))
 }
 
-fn parse(path: &str) -> io::Result<Vec<Vec<String>>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-
-    let mut parsed = Vec::new();
-    for line in lines.into_iter().flatten() {
-        let words: Vec<String> = line.split_whitespace().map(String::from).collect();
-        parsed.push(words);
-    }
-
-    Ok(parsed)
-}
-
-fn parse_range(range_str: &str) -> RangeInclusive<usize> {
-    let parts: Vec<_> = range_str.splitn(2, '-').map(|s| s.trim()).collect();
-    assert_eq!(parts.len(), 2);
-    let (left, right) = (parts[0], parts[1]);
-    let left_num = left.parse::<usize>().expect("should be a positive number");
-    let right_num = right.parse::<usize>().expect("should be a positive number");
-    RangeInclusive::new(left_num, right_num)
-}
-
-fn fully_contains(left: &RangeInclusive<usize>, right: &RangeInclusive<usize>) -> bool {
-    left.contains(right.start()) && left.contains(right.end())
-}
-
-fn solve(input: Vec<Vec<String>>) -> u64 {
-    // Parse the input
-    input
-        .into_iter()
-        .map(|line| {
-            assert_eq!(line.len(), 1);
-            let line = &line[0][..];
-            let parts: Vec<_> = line.splitn(2, ',').map(|s| s.trim()).collect();
-            assert_eq!(parts.len(), 2);
-            let (left, right) = (parts[0], parts[1]);
-            let left = parse_range(left);
-            let right = parse_range(right);
-            (left, right)
-        })
-        .filter(|(left, right)| fully_contains(left, right) || fully_contains(right, left))
-        .count() as u64
+pub fn solve(input: &[Group]) -> u64 {
+    input.iter().filter(|group| contains_another(group)).count() as u64
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day04;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = parse("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day04::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 2)
     }
 }
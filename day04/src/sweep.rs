@@ -0,0 +1,143 @@
+use crate::{intervals::IntervalSet, Section};
+use std::{collections::BTreeSet, ops::RangeInclusive};
+
+/// Maximal runs of sections assigned to the same number of elves (at least
+/// one), in order
+pub fn coverage(assignments: &[RangeInclusive<Section>]) -> Vec<(RangeInclusive<Section>, usize)> {
+    // +1 where a range starts and -1 after it ends
+    let mut events: Vec<(Section, i64)> = vec![];
+    for range in assignments.iter().filter(|range| !range.is_empty()) {
+        events.push((*range.start(), 1));
+        if let Some(after) = range.end().checked_add(1) {
+            events.push((after, -1));
+        }
+    }
+    events.sort_unstable();
+
+    let mut segments: Vec<(RangeInclusive<Section>, usize)> = vec![];
+    let mut depth = 0;
+    let mut i = 0;
+    while i < events.len() {
+        let section = events[i].0;
+        while events.get(i).is_some_and(|&(next, _)| next == section) {
+            depth += events[i].1;
+            i += 1;
+        }
+        if depth == 0 {
+            continue;
+        }
+        // The depth holds until the next event
+        let end = events.get(i).map_or(Section::MAX, |&(next, _)| next - 1);
+        let depth = depth as usize;
+        match segments.last_mut() {
+            Some((range, d)) if *d == depth && range.end().checked_add(1) == Some(section) => {
+                *range = *range.start()..=end;
+            }
+            _ => segments.push((section..=end, depth)),
+        }
+    }
+    segments
+}
+
+/// Every pair of `assignments` (by index, the lowest first) that share some
+/// section, sorted. Takes `O(n log n + k)` for `k` pairs
+pub fn overlapping_pairs(assignments: &[RangeInclusive<Section>]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..assignments.len())
+        .filter(|&i| !assignments[i].is_empty())
+        .collect();
+    order.sort_unstable_by_key(|&i| *assignments[i].start());
+
+    // Ranges that started before the current one, by their end
+    let mut active: BTreeSet<(Section, usize)> = BTreeSet::new();
+    let mut pairs = vec![];
+    for i in order {
+        let start = *assignments[i].start();
+        while let Some(&(end, j)) = active.first() {
+            if end >= start {
+                break;
+            }
+            active.remove(&(end, j));
+        }
+        pairs.extend(active.iter().map(|&(_, j)| (i.min(j), i.max(j))));
+        active.insert((*assignments[i].end(), i));
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// How the sections of a set of assignments are shared between elves
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Analysis {
+    /// Pairs of assignments (by index) that share some section
+    pub overlapping: Vec<(usize, usize)>,
+    /// Largest number of elves assigned to the same section
+    pub max_depth: usize,
+    /// Sections assigned to `max_depth` elves
+    pub deepest: IntervalSet,
+}
+
+pub fn analyze(assignments: &[RangeInclusive<Section>]) -> Analysis {
+    let coverage = coverage(assignments);
+    let max_depth = coverage.iter().map(|&(_, depth)| depth).max().unwrap_or(0);
+    let deepest = coverage
+        .into_iter()
+        .filter(|&(_, depth)| depth == max_depth)
+        .map(|(range, _)| range)
+        .collect();
+    Analysis {
+        overlapping: overlapping_pairs(assignments),
+        max_depth,
+        deepest,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use aoc_utils::Solution;
+
+    fn sample() -> Vec<RangeInclusive<Section>> {
+        Day04::parse(include_str!("../sample.txt"))
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    #[test]
+    fn test_coverage() {
+        assert_eq!(
+            coverage(&[1..=4, 3..=6, 5..=5, 8..=8, 7..=7]),
+            vec![(1..=2, 1), (3..=5, 2), (6..=8, 1)]
+        );
+        assert_eq!(coverage(&[0..=Section::MAX]), vec![(0..=Section::MAX, 1)]);
+        assert!(coverage(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_analyze() {
+        let assignments = sample();
+        let analysis = analyze(&assignments);
+
+        let mut expected = vec![];
+        for i in 0..assignments.len() {
+            for j in i + 1..assignments.len() {
+                let (a, b) = (&assignments[i], &assignments[j]);
+                if a.start() <= b.end() && b.start() <= a.end() {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert_eq!(analysis.overlapping, expected);
+
+        let depth = |section| assignments.iter().filter(|r| r.contains(&section)).count();
+        let max_depth = (0..10).map(depth).max().unwrap();
+        assert_eq!(analysis.max_depth, max_depth);
+        let deepest: IntervalSet = (0..10)
+            .filter(|&s| depth(s) == max_depth)
+            .map(|s| s..=s)
+            .collect();
+        assert_eq!(analysis.deepest, deepest);
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_utils::input::InputArgs;
+use clap::Parser;
+use day05::{crane, planner, stacks::Stacks, validate, Procedure};
+use std::{io, path::Path, process::ExitCode};
+
+/// Run the rearrangement procedure with any crane model
+#[derive(Debug, Parser)]
+struct Args {
+    /// Crane model: `9000`, `9001`, `limited:N`, `bottom` or `rotating`
+    #[arg(short, long, default_value = "9000")]
+    model: String,
+    /// Only check the procedure, reporting every illegal move
+    #[arg(long)]
+    check: bool,
+    /// Allow empty stacks at the end, shown as a space in the message
+    #[arg(long)]
+    tolerant: bool,
+    /// Instead of running the moves of the input, find a shortest list of
+    /// moves from its drawing that leaves these crates at the top (separated
+    /// by commas if their labels have several characters)
+    #[arg(long, value_name = "MESSAGE", conflicts_with_all = ["check", "tolerant"])]
+    plan: Option<String>,
+    /// Give up planning after visiting this many states of the stacks
+    #[arg(long, default_value = "1000000")]
+    max_states: usize,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+fn main() -> ExitCode {
+    aoc_utils::exit(run(Args::parse()))
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let model =
+        crane::model(&args.model).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let input = args.input.source(Path::new(".")).read()?;
+    let procedure = Procedure::parse(&input)?;
+    if let Some(target) = args.plan {
+        let start = Stacks::from_crates(&procedure.stacks);
+        let moves = planner::plan(model.as_ref(), &start, &target, args.max_states)
+            .map_err(|e| io::Error::new(io::ErrorKind::NotFound, e))?;
+        print!("{}", planner::render(&moves));
+        return Ok(());
+    }
+    let report = validate::dry_run(model.as_ref(), &procedure);
+    for illegal in &report.illegal {
+        eprintln!("{illegal}");
+    }
+    if !report.is_valid() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} illegal moves", report.illegal.len()),
+        ));
+    }
+    let message = report
+        .message(args.tolerant)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    if args.check {
+        println!("ok");
+    } else {
+        println!("{message}");
+    }
+    Ok(())
+}
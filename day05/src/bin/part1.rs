@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day05::Day05;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day05>(Part::One)
+}
@@ -0,0 +1,214 @@
+use crate::{stacks::Stacks, Move};
+
+/// How a crane moves crates between stacks
+pub trait CraneModel {
+    /// Name accepted by `model` to select it
+    fn name(&self) -> String;
+
+    /// Check that the crane can make the move with the current `stacks`
+    fn check(&self, stacks: &Stacks, m: &Move) -> Result<(), String> {
+        for stack in [m.from, m.to] {
+            if stack >= stacks.len() {
+                return Err(format!("there is no stack {}", stack + 1));
+            }
+        }
+        let available = stacks.stack(m.from).len();
+        if m.count > available {
+            return Err(format!(
+                "can not move {} crates from stack {}, which has {available}",
+                m.count,
+                m.from + 1
+            ));
+        }
+        Ok(())
+    }
+
+    /// Make the move, which must have passed `check`
+    fn apply(&self, stacks: &mut Stacks, m: &Move);
+}
+
+/// Moves one crate at a time, reversing their order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrateMover9000;
+
+impl CraneModel for CrateMover9000 {
+    fn name(&self) -> String {
+        "9000".to_string()
+    }
+
+    fn apply(&self, stacks: &mut Stacks, m: &Move) {
+        stacks.shift(m.count, m.from, m.to);
+    }
+}
+
+/// Moves all the crates at once, keeping their order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrateMover9001;
+
+impl CraneModel for CrateMover9001 {
+    fn name(&self) -> String {
+        "9001".to_string()
+    }
+
+    fn apply(&self, stacks: &mut Stacks, m: &Move) {
+        stacks.shift_many(m.count, m.from, m.to);
+    }
+}
+
+/// Lifts at most `capacity` crates at a time, keeping the order of each lift
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limited {
+    pub capacity: usize,
+}
+
+impl CraneModel for Limited {
+    fn name(&self) -> String {
+        format!("limited:{}", self.capacity)
+    }
+
+    fn apply(&self, stacks: &mut Stacks, m: &Move) {
+        let mut left = m.count;
+        while left > 0 {
+            let lift = left.min(self.capacity);
+            stacks.shift_many(lift, m.from, m.to);
+            left -= lift;
+        }
+    }
+}
+
+/// Pulls the crates from the bottom of the stack, keeping their order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BottomPull;
+
+impl CraneModel for BottomPull {
+    fn name(&self) -> String {
+        "bottom".to_string()
+    }
+
+    fn apply(&self, stacks: &mut Stacks, m: &Move) {
+        let moved: Vec<_> = stacks.stack_mut(m.from).drain(..m.count).collect();
+        stacks.stack_mut(m.to).extend(moved);
+    }
+}
+
+/// Lifts all the crates at once but rotates the grab on the way, so the
+/// top crate ends up at the bottom of the ones moved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotating;
+
+impl CraneModel for Rotating {
+    fn name(&self) -> String {
+        "rotating".to_string()
+    }
+
+    fn apply(&self, stacks: &mut Stacks, m: &Move) {
+        let from = stacks.stack_mut(m.from);
+        let mut moved = from.split_off(from.len() - m.count);
+        moved.rotate_right(1.min(m.count));
+        stacks.stack_mut(m.to).extend(moved);
+    }
+}
+
+/// Crane model with the given name: `9000`, `9001`, `limited:N`, `bottom`
+/// or `rotating`
+pub fn model(name: &str) -> Result<Box<dyn CraneModel>, String> {
+    match name {
+        "9000" => Ok(Box::new(CrateMover9000)),
+        "9001" => Ok(Box::new(CrateMover9001)),
+        "bottom" => Ok(Box::new(BottomPull)),
+        "rotating" => Ok(Box::new(Rotating)),
+        _ => {
+            let capacity = name
+                .strip_prefix("limited:")
+                .ok_or_else(|| format!("unknown crane model `{name}`"))?;
+            match capacity.parse() {
+                Ok(capacity @ 1..) => Ok(Box::new(Limited { capacity })),
+                _ => Err(format!("invalid crane capacity `{capacity}`")),
+            }
+        }
+    }
+}
+
+/// Make the `moves` from the `start` with the crane `model`, checking each
+/// one first. Fails with the index of the first move that is not possible
+pub fn run(
+    model: &dyn CraneModel,
+    start: &Stacks,
+    moves: &[Move],
+) -> Result<Stacks, (usize, String)> {
+    let mut stacks = start.clone();
+    for (i, m) in moves.iter().enumerate() {
+        model.check(&stacks, m).map_err(|e| (i, e))?;
+        model.apply(&mut stacks, m);
+    }
+    Ok(stacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day05;
+    use aoc_utils::Solution;
+
+    fn message(name: &str) -> String {
+        let procedure = Day05::parse(include_str!("../sample.txt")).unwrap();
+        let start = Stacks::from_crates(&procedure.stacks);
+        let model = model(name).unwrap();
+        assert_eq!(model.name(), name);
+        run(model.as_ref(), &start, &procedure.moves)
+            .unwrap()
+            .message()
+    }
+
+    #[test]
+    fn test_models() {
+        assert_eq!(message("9000"), "CMZ");
+        assert_eq!(message("9001"), "MCD");
+        assert_eq!(message("limited:1"), "CMZ");
+        assert_eq!(message("limited:100"), "MCD");
+
+        let mut stacks = Stacks::from_crates(&[vec!['A', 'B', 'C', 'D'], vec![]]);
+        let m = Move {
+            count: 3,
+            from: 0,
+            to: 1,
+        };
+        let mut limited = stacks.clone();
+        Limited { capacity: 2 }.apply(&mut limited, &m);
+        assert_eq!(limited.stack(1), ["C", "D", "B"]);
+        let mut bottom = stacks.clone();
+        BottomPull.apply(&mut bottom, &m);
+        assert_eq!(bottom.stack(0), ["D"]);
+        assert_eq!(bottom.stack(1), ["A", "B", "C"]);
+        Rotating.apply(&mut stacks, &m);
+        assert_eq!(stacks.stack(1), ["D", "B", "C"]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(model("9002").is_err());
+        assert!(model("limited:0").is_err());
+
+        let start = Stacks::from_crates(&[vec!['A'], vec![]]);
+        let moves = [
+            Move {
+                count: 1,
+                from: 0,
+                to: 1,
+            },
+            Move {
+                count: 1,
+                from: 0,
+                to: 1,
+            },
+        ];
+        let err = run(&CrateMover9000, &start, &moves).unwrap_err();
+        assert_eq!(
+            err,
+            (
+                1,
+                "can not move 1 crates from stack 1, which has 0".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use aoc_utils::parse::{self, ErrorKind, Line, ParseError};
+use std::{fmt::Display, str::FromStr};
+
+/// Width of the slot of every stack: enough for the widest crate (with its
+/// brackets) and stack number, and at least 3 as in the puzzle
+fn slot_width(labels: impl Iterator<Item = usize>, count: usize) -> usize {
+    labels
+        .map(|len| len + 2)
+        .chain([3, count.to_string().len()])
+        .max()
+        .unwrap()
+}
+
+/// Column (from 0) where the number of the stack `i` starts in its slot
+fn number_column(i: usize, width: usize) -> usize {
+    let len = (i + 1).to_string().len();
+    i * (width + 1) + (width - len) / 2
+}
+
+/// Draw the `stacks` (each one from the bottom to the top) in the format of
+/// the puzzle input: a row for each level with the crates as `[A]` in slots
+/// separated by a space, and the stack numbers centered below them. Every
+/// line is padded to the full width. Labels can not have whitespace or
+/// brackets
+pub fn render<T: Display>(stacks: &[Vec<T>]) -> String {
+    let labels: Vec<Vec<String>> = stacks
+        .iter()
+        .map(|stack| stack.iter().map(|label| label.to_string()).collect())
+        .collect();
+    let width = slot_width(
+        labels.iter().flatten().map(|label| label.chars().count()),
+        stacks.len(),
+    );
+    let height = labels.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let slots: Vec<String> = labels
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(label) => format!("{:<width$}", format!("[{label}]")),
+                None => " ".repeat(width),
+            })
+            .collect();
+        drawing += &slots.join(" ");
+        drawing.push('\n');
+    }
+    let numbers: Vec<String> = (0..stacks.len())
+        .map(|i| {
+            let padding = number_column(i, width) - i * (width + 1);
+            format!("{:<width$}", format!("{}{}", " ".repeat(padding), i + 1))
+        })
+        .collect();
+    drawing += &numbers.join(" ");
+    drawing.push('\n');
+    drawing
+}
+
+/// A crate of a line of the drawing, with the char column where it starts
+struct Crate<'a> {
+    column: usize,
+    /// Whole crate, with its brackets
+    text: &'a str,
+    label: &'a str,
+}
+
+fn crates<'a>(line: &Line<'a>) -> Result<Vec<Crate<'a>>, ParseError> {
+    let mut crates = vec![];
+    let mut chars = line.text.char_indices().enumerate().peekable();
+    while let Some((column, (start, c))) = chars.next() {
+        match c {
+            ' ' => {}
+            '[' => {
+                let rest = &line.text[start + 1..];
+                let end = rest
+                    .find(|c: char| c == ']' || c == '[' || c.is_whitespace())
+                    .filter(|&end| rest[end..].starts_with(']'))
+                    .ok_or_else(|| {
+                        let at = rest.find(|c: char| c == '[' || c.is_whitespace());
+                        let at = at.map_or(line.end(), |at| &rest[at..at + 1]);
+                        line.expected(at, "`]`")
+                    })?;
+                let label = &rest[..end];
+                let text = &line.text[start..start + end + 2];
+                if label.is_empty() {
+                    return Err(line.expected(&rest[..1], "a crate label"));
+                }
+                crates.push(Crate {
+                    column,
+                    text,
+                    label,
+                });
+                // Skip the label and the closing bracket
+                for _ in 0..label.chars().count() + 1 {
+                    chars.next();
+                }
+            }
+            _ => {
+                let at = &line.text[start..start + c.len_utf8()];
+                return Err(line.expected(at, "a crate like `[A]` or an empty slot"));
+            }
+        }
+    }
+    Ok(crates)
+}
+
+/// Parse the lines of a drawing made by `render`, the last of which has the
+/// stack numbers. Each crate label is parsed as a `T`
+pub fn parse_lines<T>(lines: &[Line]) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let Some((numbers, crate_lines)) = lines.split_last() else {
+        return Err(Line::new(1, "").expected("", "a drawing of the stacks"));
+    };
+    let count = numbers.words().count();
+    if count == 0 {
+        return Err(numbers.expected(numbers.end(), "the stack numbers"));
+    }
+    let crate_lines = crate_lines
+        .iter()
+        .map(|line| Ok((line, crates(line)?)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let width = slot_width(
+        crate_lines
+            .iter()
+            .flat_map(|(_, crates)| crates)
+            .map(|c| c.label.chars().count()),
+        count,
+    );
+
+    for (i, word) in numbers.words().enumerate() {
+        let expected = format!("stack number {}", i + 1);
+        if numbers.number::<usize>(word).ok() != Some(i + 1) {
+            return Err(numbers.expected(word, expected));
+        }
+        let column = numbers.text[..word.as_ptr() as usize - numbers.text.as_ptr() as usize]
+            .chars()
+            .count();
+        if column != number_column(i, width) {
+            return Err(numbers.invalid(word, "stack number not centered below its stack"));
+        }
+    }
+
+    let mut stacks: Vec<Vec<T>> = (0..count).map(|_| Vec::new()).collect();
+    for (level, (line, crates)) in crate_lines.iter().rev().enumerate() {
+        for c in crates {
+            if c.column % (width + 1) != 0 {
+                return Err(line.invalid(c.text, "crate not aligned with the stacks"));
+            }
+            let i = c.column / (width + 1);
+            if i >= count {
+                return Err(line.invalid(c.text, "crate outside of the stacks"));
+            }
+            if stacks[i].len() < level {
+                return Err(line.invalid(c.text, "crate floating in the air"));
+            }
+            let label = c
+                .label
+                .parse()
+                .map_err(|e: T::Err| line.error(c.label, ErrorKind::Invalid(e.to_string())))?;
+            stacks[i].push(label);
+        }
+    }
+    Ok(stacks)
+}
+
+/// Parse a drawing made by `render`, up to the first blank line
+pub fn parse<T>(input: &str) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let lines: Vec<_> = parse::lines(input)
+        .take_while(|line| !line.text.trim().is_empty())
+        .collect();
+    parse_lines(&lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const SAMPLE: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n";
+
+    #[test]
+    fn test_render() {
+        let stacks = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        assert_eq!(render(&stacks), SAMPLE);
+        assert!(include_str!("../sample.txt").starts_with(SAMPLE));
+
+        let stacks = vec![vec!["AB".to_string()], vec![], vec!["C".to_string()]];
+        assert_eq!(render(&stacks), "[AB]      [C] \n 1    2    3  \n");
+
+        let mut stacks = vec![vec![]; 10];
+        stacks[9].push('X');
+        let drawing = render(&stacks);
+        assert!(drawing.ends_with(" 9  10 \n"));
+        assert!(drawing.starts_with(&" ".repeat(36)));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse::<char>(SAMPLE),
+            Ok(vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']])
+        );
+        // The padding at the end of the lines is optional
+        let stacks: Vec<Vec<String>> = parse("[AB]      [C]\n 1    2    3\n").unwrap();
+        assert_eq!(stacks, vec![vec!["AB"], vec![], vec!["C"]]);
+
+        let err = parse::<String>("[AB] [C]\n 1   2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        let err = parse::<String>("[A]  [C]\n 1   2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        let err = parse::<String>("[A] [C\n 1   2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        let err = parse::<char>("[A]  [CD]\n 1    2\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 7, "CD"));
+    }
+
+    fn stacks() -> impl Strategy<Value = Vec<Vec<String>>> {
+        prop::collection::vec(prop::collection::vec("[A-Za-z0-9#*]{1,4}", 0..6), 1..15)
+    }
+
+    proptest! {
+        #[test]
+        fn test_roundtrip(stacks in stacks()) {
+            let drawing = render(&stacks);
+            prop_assert_eq!(parse::<String>(&drawing), Ok(stacks));
+            // Every line has the same width
+            let widths: Vec<_> = drawing.lines().map(|line| line.chars().count()).collect();
+            prop_assert!(widths.windows(2).all(|w| w[0] == w[1]));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use aoc_utils::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+use crane::{CraneModel, CrateMover9000};
+use stacks::Stacks;
+use std::fmt::{self, Display};
+
+pub mod crane;
+pub mod drawing;
+pub mod part1;
+pub mod part2;
+pub mod planner;
+pub mod replay;
+pub mod stacks;
+pub mod validate;
+
+/// Label of a crate, which may have several characters
+pub type Crate = String;
+
+/// Move `count` crates from the stack `from` to the stack `to` (0-based)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Display for Move {
+    /// Write the move as in the puzzle input
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+/// Starting drawing of the stacks and the moves of the rearrangement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Procedure {
+    /// Crates of each stack, from the bottom to the top
+    pub stacks: Vec<Vec<Crate>>,
+    pub moves: Vec<Move>,
+    /// Line of the input of each move
+    pub lines: Vec<usize>,
+}
+
+/// Parse a move. Stacks that do not exist are left for the crane to reject,
+/// so that every illegal move can be reported
+fn parse_move(line: &Line) -> Result<Move, ParseError> {
+    let mut words = line.words();
+    let stack = |words: &mut parse::Words, what| {
+        let word = words.word(what)?;
+        match line.number::<usize>(word)? {
+            0 => Err(line.invalid(word, "there is no stack 0")),
+            n => Ok(n - 1),
+        }
+    };
+    words.keyword("move")?;
+    let count = words.number("the number of crates")?;
+    words.keyword("from")?;
+    let from = stack(&mut words, "the origin stack")?;
+    words.keyword("to")?;
+    let to = stack(&mut words, "the destination stack")?;
+    words.finish()?;
+    Ok(Move { count, from, to })
+}
+
+impl Procedure {
+    /// Parse the drawing and the moves without checking that the moves are
+    /// possible, so that a dry run can report every illegal one
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(input);
+        let drawing: Vec<_> = lines
+            .by_ref()
+            .take_while(|line| !line.text.trim().is_empty())
+            .collect();
+        let stacks = drawing::parse_lines(&drawing)?;
+
+        let (moves, lines) = lines
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| Ok((parse_move(&line)?, line.number)))
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self {
+            stacks,
+            moves,
+            lines,
+        })
+    }
+}
+
+/// Check that every move of the `procedure` parsed from `input` is possible
+/// and that no stack is empty at the end, as the puzzle needs. Every crane
+/// of the puzzle leaves the same number of crates in each stack
+fn check(input: &str, procedure: &Procedure) -> Result<(), ParseError> {
+    let lines: Vec<_> = parse::lines(input).collect();
+    // Line of the move `i`, with its words: `move N from A to B`
+    let words = |i: usize| {
+        let line = lines[procedure.lines[i] - 1];
+        (line, line.words().collect::<Vec<_>>())
+    };
+    let mut stacks = Stacks::from_crates(&procedure.stacks);
+    // Last move that took crates from each stack
+    let mut taken = vec![None; stacks.len()];
+    for (i, m) in procedure.moves.iter().enumerate() {
+        if let Err(reason) = CrateMover9000.check(&stacks, m) {
+            let (line, words) = words(i);
+            let at = match m {
+                m if m.from >= stacks.len() => words[3],
+                m if m.to >= stacks.len() => words[5],
+                _ => words[1],
+            };
+            return Err(line.invalid(at, reason));
+        }
+        CrateMover9000.apply(&mut stacks, m);
+        taken[m.from] = Some(i);
+    }
+    if let Err(empty) = stacks.checked_message() {
+        let stack = empty[0];
+        let message = format!("stack {} is empty at the end", stack + 1);
+        return Err(match taken[stack] {
+            Some(i) => {
+                let (line, words) = words(i);
+                line.invalid(words[1], message)
+            }
+            None => {
+                let numbers = lines.iter().take_while(|line| !line.text.trim().is_empty());
+                let numbers = numbers.last().copied().unwrap_or(Line::new(1, ""));
+                let number = numbers.words().nth(stack).unwrap_or(numbers.end());
+                numbers.invalid(number, message)
+            }
+        });
+    }
+    Ok(())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let procedure = Procedure::parse(input)?;
+        check(input, &procedure)?;
+        Ok(procedure)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::parse::ErrorKind;
+
+    #[test]
+    fn test_parse() {
+        let procedure = Day05::parse(include_str!("../sample.txt")).unwrap();
+        assert_eq!(
+            procedure.stacks,
+            vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]]
+        );
+        assert_eq!(
+            procedure.moves[0],
+            Move {
+                count: 1,
+                from: 1,
+                to: 0
+            }
+        );
+        assert_eq!(procedure.lines, vec![6, 7, 8, 9]);
+
+        let procedure =
+            Procedure::parse("[AB]      [C] \n 1    2    3  \n\nmove 1 from 1 to 2\n").unwrap();
+        assert_eq!(procedure.stacks, vec![vec!["AB"], vec![], vec!["C"]]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day05::parse("[A] (b)\n 1   2\n\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.text, "(");
+
+        let err = Day05::parse("[A] [BC]\n 1   2\n\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+
+        let err = Day05::parse("    [A]\n[B]\n 1   2\n\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+
+        let err = Day05::parse("[A] [B]\n 1   2\n\nmove 1 from 0 to 2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 13));
+        assert_eq!(err.text, "0");
+
+        let err = Day05::parse("[A] [B]\n 1   2\n\nmove 1 to 2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 8));
+        assert_eq!(err.text, "to");
+    }
+
+    #[test]
+    fn test_impossible_moves() {
+        let err = Day05::parse("[A] [B]\n 1   2\n\nmove 1 from 1 to 2\n\nmove 1 from 3 to 2\n")
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (6, 13, "3"));
+        assert_eq!(
+            err.kind,
+            ErrorKind::Invalid("there is no stack 3".to_string())
+        );
+
+        let err = Day05::parse("[A] [B]\n 1   2\n\nmove 2 from 1 to 2\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 6, "2"));
+
+        let err = Day05::parse("[A] [B]\n 1   2\n\nmove 1 from 2 to 1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 6));
+        assert_eq!(
+            err.kind,
+            ErrorKind::Invalid("stack 2 is empty at the end".to_string())
+        );
+
+        let err = Day05::parse("[A]\n 1   2\n\nmove 1 from 1 to 1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, "2"));
+
+        // They are left to the crane in a dry run
+        let procedure = Procedure::parse("[A] [B]\n 1   2\n\nmove 2 from 1 to 3\n").unwrap();
+        assert_eq!(procedure.moves.len(), 1);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{crane::CraneModel, stacks::Stacks, Move};
+use std::collections::{HashSet, VecDeque};
+
+/// Top crate of each stack in a `target`: their labels separated by commas,
+/// or the message itself if every label has a single character. An empty
+/// label (or a space in a message) stands for an empty stack
+pub fn parse_target(target: &str) -> Vec<&str> {
+    if target.contains(',') {
+        return target.split(',').map(str::trim).collect();
+    }
+    target
+        .char_indices()
+        .map(|(i, c)| match c {
+            ' ' => "",
+            _ => &target[i..i + c.len_utf8()],
+        })
+        .collect()
+}
+
+/// Find a shortest sequence of moves that the crane `model` can make from
+/// the `start` to leave the crates of the `target` (see `parse_target`) at
+/// the top of the stacks. The search gives up after visiting `max_states`
+/// different states of the stacks
+pub fn plan(
+    model: &dyn CraneModel,
+    start: &Stacks,
+    target: &str,
+    max_states: usize,
+) -> Result<Vec<Move>, String> {
+    let tops = parse_target(target);
+    if tops.len() != start.len() {
+        return Err(format!(
+            "the target has {} crates but there are {} stacks",
+            tops.len(),
+            start.len()
+        ));
+    }
+    let reached = |stacks: &Stacks| {
+        let found = stacks.tops().into_iter();
+        found
+            .map(|top| top.map_or("", String::as_str))
+            .eq(tops.iter().copied())
+    };
+    if reached(start) {
+        return Ok(vec![]);
+    }
+
+    // Every state found, with the one it comes from and the move that leads
+    // to it
+    let mut states: Vec<(Stacks, Option<(usize, Move)>)> = vec![(start.clone(), None)];
+    let path = |states: &[(Stacks, Option<(usize, Move)>)], mut state: usize| {
+        let mut moves = vec![];
+        while let Some((parent, m)) = states[state].1 {
+            moves.push(m);
+            state = parent;
+        }
+        moves.reverse();
+        moves
+    };
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([0]);
+    while let Some(current) = queue.pop_front() {
+        let stacks = states[current].0.clone();
+        for from in 0..stacks.len() {
+            for to in (0..stacks.len()).filter(|&to| to != from) {
+                for count in 1..=stacks.stack(from).len() {
+                    let m = Move { count, from, to };
+                    if model.check(&stacks, &m).is_err() {
+                        continue;
+                    }
+                    let mut next = stacks.clone();
+                    model.apply(&mut next, &m);
+                    if seen.contains(&next) {
+                        continue;
+                    }
+                    // The goal is tested as soon as it is found, so that a
+                    // plan is never missed for the lack of room to store it
+                    let found = reached(&next);
+                    if !found && seen.len() >= max_states {
+                        return Err(format!("no plan found within {max_states} states"));
+                    }
+                    seen.insert(next.clone());
+                    states.push((next, Some((current, m))));
+                    if found {
+                        return Ok(path(&states, states.len() - 1));
+                    }
+                    queue.push_back(states.len() - 1);
+                }
+            }
+        }
+    }
+    Err(format!("`{target}` can not be reached"))
+}
+
+/// Write the `moves` in the syntax of the puzzle input, one per line
+pub fn render(moves: &[Move]) -> String {
+    moves.iter().map(|m| format!("{m}\n")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crane::{self, CrateMover9000, CrateMover9001},
+        Day05,
+    };
+    use aoc_utils::Solution;
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!(parse_target("CMZ"), ["C", "M", "Z"]);
+        assert_eq!(parse_target("B "), ["B", ""]);
+        assert_eq!(parse_target("AB,,C"), ["AB", "", "C"]);
+    }
+
+    #[test]
+    fn test_plan() {
+        let start = Stacks::from_crates(&[vec!['A', 'B'], vec![]]);
+        let moves = plan(&CrateMover9000, &start, "AB", 1000).unwrap();
+        assert_eq!(render(&moves), "move 1 from 1 to 2\n");
+        assert_eq!(plan(&CrateMover9000, &start, "B ", 1000), Ok(vec![]));
+        // The goal counts even when it fills the budget
+        assert_eq!(plan(&CrateMover9000, &start, "AB", 2), Ok(moves.clone()));
+        assert_eq!(plan(&CrateMover9000, &start, "AB", 1), Ok(moves));
+
+        // Labels with several characters
+        let start = Stacks::from_crates(&[vec!["AA", "BB"], vec![], vec!["C"]]);
+        let moves = plan(&CrateMover9000, &start, "AA, BB, C", 1000).unwrap();
+        assert_eq!(render(&moves), "move 1 from 1 to 2\n");
+        assert_eq!(plan(&CrateMover9000, &start, "BB,,C", 1000), Ok(vec![]));
+
+        let procedure = Day05::parse(include_str!("../sample.txt")).unwrap();
+        let start = Stacks::from_crates(&procedure.stacks);
+        for (model, target) in [
+            (&CrateMover9000 as &dyn CraneModel, "CMZ"),
+            (&CrateMover9001, "MCD"),
+        ] {
+            let moves = plan(model, &start, target, 100_000).unwrap();
+            // The moves of the sample get there too
+            assert!(moves.len() <= procedure.moves.len());
+            let end = crane::run(model, &start, &moves).unwrap();
+            assert_eq!(end.message(), target);
+
+            // The rendered moves are a valid procedure
+            let input = format!(
+                "{}\n{}",
+                crate::drawing::render(&procedure.stacks),
+                render(&moves)
+            );
+            assert_eq!(Day05::parse(&input).unwrap().moves, moves);
+        }
+    }
+
+    #[test]
+    fn test_impossible() {
+        let start = Stacks::from_crates(&[vec!['A'], vec![]]);
+        assert!(plan(&CrateMover9000, &start, "AB", 1000).is_err());
+        assert!(plan(&CrateMover9000, &start, "A", 1000).is_err());
+        assert_eq!(
+            plan(&CrateMover9000, &start, "BA", 1000),
+            Err("`BA` can not be reached".to_string())
+        );
+
+        let procedure = Day05::parse(include_str!("../sample.txt")).unwrap();
+        let start = Stacks::from_crates(&procedure.stacks);
+        assert_eq!(
+            plan(&CrateMover9000, &start, "QQQ", 10),
+            Err("no plan found within 10 states".to_string())
+        );
+    }
+}
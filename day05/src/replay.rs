@@ -0,0 +1,241 @@
+use crate::{crane::CraneModel, stacks::Stacks, Crate, Move};
+
+/// Change of the crates of a stack, from the bottom to the top
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub stack: usize,
+    /// Number of crates at the bottom that do not change
+    pub keep: usize,
+    /// Crates above them before the change
+    pub removed: Vec<Crate>,
+    /// Crates above them after the change
+    pub added: Vec<Crate>,
+}
+
+impl Change {
+    /// Change from the crates `before` to the ones `after`, if they differ
+    fn between(stack: usize, before: &[Crate], after: &[Crate]) -> Option<Self> {
+        let keep = before.iter().zip(after).take_while(|(a, b)| a == b).count();
+        if keep == before.len() && keep == after.len() {
+            return None;
+        }
+        Some(Self {
+            stack,
+            keep,
+            removed: before[keep..].to_vec(),
+            added: after[keep..].to_vec(),
+        })
+    }
+
+    fn apply(&self, stacks: &mut Stacks) {
+        let stack = stacks.stack_mut(self.stack);
+        stack.truncate(self.keep);
+        stack.extend_from_slice(&self.added);
+    }
+
+    fn undo(&self, stacks: &mut Stacks) {
+        let stack = stacks.stack_mut(self.stack);
+        stack.truncate(self.keep);
+        stack.extend_from_slice(&self.removed);
+    }
+}
+
+/// Changes made by every stack between `before` and `after`
+pub fn diff(before: &Stacks, after: &Stacks) -> Vec<Change> {
+    (0..before.len())
+        .filter_map(|i| Change::between(i, before.stack(i), after.stack(i)))
+        .collect()
+}
+
+/// Recorded run of a rearrangement procedure, which can be stepped forwards
+/// and backwards since each move is stored as the changes it made
+#[derive(Debug, Clone)]
+pub struct Execution {
+    stacks: Stacks,
+    moves: Vec<Move>,
+    steps: Vec<Vec<Change>>,
+    /// Number of moves applied to `stacks`
+    position: usize,
+}
+
+impl Execution {
+    /// Record the `moves` done from the `start` by the crane `model`,
+    /// checking each one first. The execution is left at the start. Fails
+    /// with the index of the first move that is not possible
+    pub fn record(
+        model: &dyn CraneModel,
+        start: &Stacks,
+        moves: &[Move],
+    ) -> Result<Self, (usize, String)> {
+        let mut stacks = start.clone();
+        let mut steps = Vec::with_capacity(moves.len());
+        for (index, m) in moves.iter().enumerate() {
+            model.check(&stacks, m).map_err(|e| (index, e))?;
+            let before = [m.from, m.to].map(|i| stacks.stack(i).to_vec());
+            model.apply(&mut stacks, m);
+            let mut changes = vec![];
+            for (i, before) in [m.from, m.to].into_iter().zip(&before) {
+                if changes.iter().any(|c: &Change| c.stack == i) {
+                    continue;
+                }
+                changes.extend(Change::between(i, before, stacks.stack(i)));
+            }
+            steps.push(changes);
+        }
+        Ok(Self {
+            stacks: start.clone(),
+            moves: moves.to_vec(),
+            steps,
+            position: 0,
+        })
+    }
+
+    /// Number of moves of the procedure
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Number of moves applied so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// State of the stacks after the moves applied so far
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    /// Apply the next move, returning it (or `None` at the end)
+    pub fn step_forward(&mut self) -> Option<&Move> {
+        let step = self.steps.get(self.position)?;
+        for change in step {
+            change.apply(&mut self.stacks);
+        }
+        self.position += 1;
+        Some(&self.moves[self.position - 1])
+    }
+
+    /// Undo the last applied move, returning it (or `None` at the start)
+    pub fn step_back(&mut self) -> Option<&Move> {
+        self.position = self.position.checked_sub(1)?;
+        for change in self.steps[self.position].iter().rev() {
+            change.undo(&mut self.stacks);
+        }
+        Some(&self.moves[self.position])
+    }
+
+    /// Go to the state after the first `k` moves, which fails if there are
+    /// less than `k` moves
+    pub fn jump(&mut self, k: usize) -> Result<(), String> {
+        if k > self.len() {
+            return Err(format!("there are only {} moves", self.len()));
+        }
+        while self.position < k {
+            self.step_forward();
+        }
+        while self.position > k {
+            self.step_back();
+        }
+        Ok(())
+    }
+
+    /// State of the stacks after the first `k` moves
+    pub fn stacks_at(&self, k: usize) -> Result<Stacks, String> {
+        let mut execution = self.clone();
+        execution.jump(k)?;
+        Ok(execution.stacks)
+    }
+
+    /// Changes of the stacks from the state after `a` moves to the one
+    /// after `b` moves
+    pub fn diff(&self, a: usize, b: usize) -> Result<Vec<Change>, String> {
+        Ok(diff(&self.stacks_at(a)?, &self.stacks_at(b)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crane::{CraneModel, CrateMover9000, CrateMover9001},
+        Day05,
+    };
+    use aoc_utils::Solution;
+
+    fn sample(model: &dyn CraneModel) -> Execution {
+        let procedure = Day05::parse(include_str!("../sample.txt")).unwrap();
+        let start = Stacks::from_crates(&procedure.stacks);
+        Execution::record(model, &start, &procedure.moves).unwrap()
+    }
+
+    #[test]
+    fn test_steps() {
+        let models: [(&dyn CraneModel, _); 2] =
+            [(&CrateMover9000, "CMZ"), (&CrateMover9001, "MCD")];
+        for (model, message) in models {
+            let mut execution = sample(model);
+            let start = execution.stacks().clone();
+            assert!(execution.step_back().is_none());
+            while execution.step_forward().is_some() {}
+            assert_eq!(execution.position(), 4);
+            assert_eq!(execution.stacks().message(), message);
+
+            let last = *execution.step_back().unwrap();
+            assert_eq!((last.count, last.from, last.to), (1, 0, 1));
+            execution.jump(0).unwrap();
+            assert_eq!(execution.stacks(), &start);
+            assert_eq!(execution.jump(5), Err("there are only 4 moves".to_string()));
+            assert_eq!(execution.position(), 0);
+        }
+    }
+
+    #[test]
+    fn test_illegal() {
+        let start = Stacks::from_crates(&[vec!["A"], vec![]]);
+        let moves = [
+            Move {
+                count: 1,
+                from: 0,
+                to: 1,
+            },
+            Move {
+                count: 1,
+                from: 2,
+                to: 1,
+            },
+        ];
+        let err = Execution::record(&CrateMover9000, &start, &moves).unwrap_err();
+        assert_eq!(err, (1, "there is no stack 3".to_string()));
+    }
+
+    #[test]
+    fn test_diff() {
+        let execution = sample(&CrateMover9000);
+        // move 1 from 2 to 1
+        assert_eq!(
+            execution.diff(0, 1),
+            Ok(vec![
+                Change {
+                    stack: 0,
+                    keep: 2,
+                    removed: vec![],
+                    added: vec!["D".to_string()],
+                },
+                Change {
+                    stack: 1,
+                    keep: 2,
+                    removed: vec!["D".to_string()],
+                    added: vec![],
+                },
+            ])
+        );
+        assert_eq!(execution.diff(2, 2), Ok(vec![]));
+        assert_eq!(execution.diff(4, 0).map(|changes| changes.len()), Ok(3));
+        assert_eq!(execution.stacks_at(4).unwrap().message(), "CMZ");
+        assert!(execution.diff(0, 9).is_err());
+    }
+}
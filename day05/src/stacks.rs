@@ -0,0 +1,99 @@
+use crate::{drawing, Crate};
+use std::fmt::Display;
+
+/// Stacks of crates, each one from the bottom to the top
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stacks {
+    stacks: Vec<Vec<Crate>>,
+}
+
+impl Stacks {
+    /// Create a `Stacks` instance from the crates of each stack (from the
+    /// bottom to the top)
+    pub fn from_crates<T: ToString>(crates: &[Vec<T>]) -> Self {
+        Self {
+            stacks: crates
+                .iter()
+                .map(|stack| stack.iter().map(T::to_string).collect())
+                .collect(),
+        }
+    }
+
+    /// Number of stacks
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    /// Crates of the stack `i`, from the bottom to the top
+    pub fn stack(&self, i: usize) -> &[Crate] {
+        &self.stacks[i]
+    }
+
+    pub(crate) fn stack_mut(&mut self, i: usize) -> &mut Vec<Crate> {
+        &mut self.stacks[i]
+    }
+
+    /// Move `count` elements from the stack `from` to stack `dest`
+    pub fn shift(&mut self, count: usize, from: usize, to: usize) {
+        assert!(from < self.stacks.len());
+        assert!(to < self.stacks.len());
+        assert!(count <= self.stacks[from].len());
+        for _ in 0..count {
+            let value = self.stacks[from].pop().unwrap();
+            self.stacks[to].push(value);
+        }
+    }
+
+    /// Move `count` elements from the stack `from` to stack `dest` many
+    /// at a time (retaining original order)
+    pub fn shift_many(&mut self, count: usize, from: usize, to: usize) {
+        assert!(from < self.stacks.len());
+        assert!(to < self.stacks.len());
+        let from_count = self.stacks[from].len();
+        assert!(count <= from_count);
+
+        let moved = self.stacks[from].split_off(from_count - count);
+        self.stacks[to].extend(moved);
+    }
+
+    /// Crate at the top of each stack, if any
+    pub fn tops(&self) -> Vec<Option<&Crate>> {
+        self.stacks.iter().map(|stack| stack.last()).collect()
+    }
+
+    /// Crate at the top of each stack
+    pub fn message(&self) -> String {
+        self.checked_message().expect("should have a crate")
+    }
+
+    /// Crate at the top of each stack, or the (0-based) stacks that are
+    /// empty if any
+    pub fn checked_message(&self) -> Result<String, Vec<usize>> {
+        let empty: Vec<usize> = (0..self.len())
+            .filter(|&i| self.stacks[i].is_empty())
+            .collect();
+        if !empty.is_empty() {
+            return Err(empty);
+        }
+        Ok(self.tolerant_message(" "))
+    }
+
+    /// Crate at the top of each stack, with `empty` for the empty ones
+    pub fn tolerant_message(&self, empty: &str) -> String {
+        self.tops()
+            .into_iter()
+            .map(|top| top.map_or(empty, String::as_str))
+            .collect()
+    }
+}
+
+impl Display for Stacks {
+    /// Draw the stacks as in the puzzle input
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&drawing::render(&self.stacks))
+    }
+}
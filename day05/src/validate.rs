@@ -0,0 +1,123 @@
+use crate::{crane::CraneModel, stacks::Stacks, Procedure};
+use std::fmt::{self, Display};
+
+/// Move of a procedure that the crane can not make
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IllegalMove {
+    /// Position of the move in the procedure (from 0)
+    pub index: usize,
+    /// Line of the input with the move
+    pub line: usize,
+    pub reason: String,
+}
+
+impl Display for IllegalMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+/// Result of checking a whole procedure without stopping at the first
+/// illegal move
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub illegal: Vec<IllegalMove>,
+    /// Final state of the stacks, after every legal move
+    pub stacks: Stacks,
+}
+
+impl Report {
+    pub fn is_valid(&self) -> bool {
+        self.illegal.is_empty()
+    }
+
+    /// Crate at the top of each stack at the end. Empty stacks are an error
+    /// unless `tolerant`, which shows them as a space
+    pub fn message(&self, tolerant: bool) -> Result<String, String> {
+        if tolerant {
+            return Ok(self.stacks.tolerant_message(" "));
+        }
+        self.stacks.checked_message().map_err(|empty| {
+            let empty: Vec<_> = empty.iter().map(|i| (i + 1).to_string()).collect();
+            format!("empty stacks at the end: {}", empty.join(", "))
+        })
+    }
+}
+
+/// Run the `procedure` with the crane `model` on a copy of its stacks,
+/// skipping and reporting every move that is not possible
+pub fn dry_run(model: &dyn CraneModel, procedure: &Procedure) -> Report {
+    let mut stacks = Stacks::from_crates(&procedure.stacks);
+    let mut illegal = vec![];
+    for (index, m) in procedure.moves.iter().enumerate() {
+        match model.check(&stacks, m) {
+            Ok(()) => model.apply(&mut stacks, m),
+            Err(reason) => illegal.push(IllegalMove {
+                index,
+                line: procedure.lines.get(index).copied().unwrap_or(0),
+                reason,
+            }),
+        }
+    }
+    Report { illegal, stacks }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crane::{CrateMover9000, CrateMover9001},
+        Day05,
+    };
+    use aoc_utils::Solution;
+
+    #[test]
+    fn test_dry_run() {
+        let procedure = Day05::parse(include_str!("../sample.txt")).unwrap();
+        let report = dry_run(&CrateMover9001, &procedure);
+        assert!(report.is_valid());
+        assert_eq!(report.message(false), Ok("MCD".to_string()));
+
+        let input = "[A]\n[B] [C]\n 1   2\n\nmove 3 from 1 to 2\nmove 2 from 1 to 2\n\nmove 2 from 1 to 2\n";
+        let procedure = Procedure::parse(input).unwrap();
+        let report = dry_run(&CrateMover9000, &procedure);
+        let lines: Vec<_> = report.illegal.iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![5, 8]);
+        assert_eq!(
+            report.illegal[0].to_string(),
+            "line 5: can not move 3 crates from stack 1, which has 2"
+        );
+        assert_eq!(
+            report.message(false),
+            Err("empty stacks at the end: 1".to_string())
+        );
+        assert_eq!(report.message(true), Ok(" B".to_string()));
+    }
+
+    #[test]
+    fn test_missing_stacks() {
+        let input =
+            "[A] [B]\n 1   2\n\nmove 1 from 3 to 2\nmove 1 from 1 to 2\nmove 1 from 2 to 4\n";
+        let procedure = Procedure::parse(input).unwrap();
+        let report = dry_run(&CrateMover9000, &procedure);
+        let illegal: Vec<_> = report.illegal.iter().map(|m| m.to_string()).collect();
+        assert_eq!(
+            illegal,
+            vec!["line 4: there is no stack 3", "line 6: there is no stack 4"]
+        );
+        assert_eq!(report.message(true), Ok(" A".to_string()));
+    }
+
+    #[test]
+    fn test_labels() {
+        let input = "[XY]          \n[A1] [B2] [C3]\n 1    2    3  \n\nmove 2 from 1 to 3\nmove 2 from 2 to 1\n";
+        let procedure = Procedure::parse(input).unwrap();
+        let report = dry_run(&CrateMover9001, &procedure);
+        assert_eq!(
+            report.illegal[0].to_string(),
+            "line 6: can not move 2 crates from stack 2, which has 1"
+        );
+        assert_eq!(report.stacks.stack(2), ["C3", "A1", "XY"]);
+        assert_eq!(report.message(true), Ok(" B2XY".to_string()));
+    }
+}
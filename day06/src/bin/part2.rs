@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day06::Day06;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day06>(Part::Two)
+}
@@ -0,0 +1,47 @@
+use aoc_utils::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input).filter(|line| !line.text.trim().is_empty());
+        let Some(datastream) = lines.next() else {
+            return Err(Line::new(1, "").expected("", "the datastream"));
+        };
+        // The datastream is a single line
+        if let Some(line) = lines.next() {
+            return Err(line.expected(line.text, "the end of the input"));
+        }
+        Ok(datastream.text.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day06::parse("mjqjpqmgbjlsp\nbvwbjplbg\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(Day06::parse("\n").is_err());
+    }
+}
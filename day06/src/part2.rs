@@ -1,26 +1,8 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{self, BufRead},
-};
+use std::collections::HashSet;
 
 const WINDOW_SIZE: usize = 14;
 
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn parse(path: &str) -> io::Result<String> {
-    let file = File::open(path)?;
-    let lines: Vec<_> = io::BufReader::new(file).lines().flatten().collect();
-    assert_eq!(lines.len(), 1);
-    Ok(lines.into_iter().next().unwrap())
-}
-
-fn solve(input: String) -> u64 {
+pub fn solve(input: &str) -> u64 {
     // Collect the chars into a String for windowed iteration
     let char_vec: Vec<char> = input.chars().collect();
     let mut char_set: HashSet<char> = HashSet::with_capacity(4);
@@ -42,39 +24,41 @@ fn solve(input: String) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day06;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = parse("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day06::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 19)
     }
 
     #[test]
     fn test_sample6() {
-        let input = parse("sample6.txt").unwrap();
-        let solution = solve(input);
+        let input = Day06::parse(include_str!("../sample6.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 23)
     }
 
     #[test]
     fn test_sample7() {
-        let input = parse("sample7.txt").unwrap();
-        let solution = solve(input);
+        let input = Day06::parse(include_str!("../sample7.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 23)
     }
 
     #[test]
     fn test_sample8() {
-        let input = parse("sample8.txt").unwrap();
-        let solution = solve(input);
+        let input = Day06::parse(include_str!("../sample8.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 29)
     }
 
     #[test]
     fn test_sample9() {
-        let input = parse("sample9.txt").unwrap();
-        let solution = solve(input);
+        let input = Day06::parse(include_str!("../sample9.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 26)
     }
 }
@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day07::Day07;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day07>(Part::Two)
+}
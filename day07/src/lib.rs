@@ -0,0 +1,84 @@
+use aoc_utils::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// A line of the terminal session: a command or the output of `ls`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalLine {
+    Cd(String),
+    Ls,
+    Dir(String),
+    File { size: usize, name: String },
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<TerminalLine>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut listing = false;
+        let mut terminal = vec![];
+        for line in parse::lines(input).filter(|line| !line.text.trim().is_empty()) {
+            let mut words = line.words();
+            let first = words.word("a command or the output of `ls`")?;
+            let terminal_line = match first {
+                "$" => match words.word("a command")? {
+                    "cd" => {
+                        listing = false;
+                        TerminalLine::Cd(words.word("a directory name")?.to_string())
+                    }
+                    "ls" if listing => return Err(line.invalid(line.text, "already listing")),
+                    "ls" => {
+                        listing = true;
+                        TerminalLine::Ls
+                    }
+                    command => return Err(line.expected(command, "`cd` or `ls`")),
+                },
+                _ if !listing => {
+                    return Err(line.invalid(first, "output found while not listing"));
+                }
+                "dir" => TerminalLine::Dir(words.word("a directory name")?.to_string()),
+                size => TerminalLine::File {
+                    size: line.number(size)?,
+                    name: words.word("a file name")?.to_string(),
+                },
+            };
+            words.finish()?;
+            terminal.push(terminal_line);
+        }
+        Ok(terminal)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day07::parse("$ cd /\n$ ls\n12ab b.txt\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "12ab");
+
+        let err = Day07::parse("$ cd /\ndir a\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = Day07::parse("$ cd /\n$ rm a\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -1,8 +1,5 @@
+use crate::TerminalLine;
 use std::collections::VecDeque;
-use std::{
-    fs::File,
-    io::{self, BufRead},
-};
 
 // FIXME(alvaro): I think as it is right now having FSDirectory and FSFile as
 // independent structs is redundant
@@ -10,6 +7,7 @@ use std::{
 #[derive(Debug, Clone)]
 struct FSDirectory {
     name: String,
+    #[allow(dead_code)]
     path: String,
     entries: Vec<Entry>,
 }
@@ -27,6 +25,7 @@ impl FSDirectory {
 #[derive(Debug, Clone)]
 struct FSFile {
     name: String,
+    #[allow(dead_code)]
     path: String,
     size: usize,
 }
@@ -55,6 +54,7 @@ impl Entry {
         }
     }
 
+    #[allow(dead_code)]
     fn get_path(&self) -> &str {
         match self {
             Entry::DirEntry(FSDirectory { path, .. }) => path.as_ref(),
@@ -62,10 +62,12 @@ impl Entry {
         }
     }
 
+    #[allow(dead_code)]
     fn is_dir(&self) -> bool {
         matches!(self, Entry::DirEntry(..))
     }
 
+    #[allow(dead_code)]
     fn is_file(&self) -> bool {
         matches!(self, Entry::FileEntry(..))
     }
@@ -117,6 +119,7 @@ impl Entry {
     }
 
     /// Helper to print the contents of the given entry
+    #[allow(dead_code)]
     fn print_with_level(&self, level: u32) {
         let prefix = "  ".repeat(level as usize * 2);
         match self {
@@ -286,60 +289,14 @@ fn join_path(left: String, right: &str) -> String {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn parse(path: &str) -> io::Result<Vec<Vec<String>>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-
-    let mut parsed = Vec::new();
-    for line in lines.into_iter().flatten() {
-        let words: Vec<String> = line.split_whitespace().map(String::from).collect();
-        parsed.push(words);
-    }
-
-    Ok(parsed)
-}
-
-fn solve(input: Vec<Vec<String>>) -> u64 {
+pub fn solve(input: &[TerminalLine]) -> u64 {
     let mut fs = FileSystem::new();
-
-    let mut listing = false;
-    for mut line in input {
-        let first = &line[0][..];
-        match first {
-            "$" => {
-                let command = &line[1][..];
-                match command {
-                    "cd" => {
-                        listing = false;
-                        let dirname = line.pop().expect("a dirname");
-                        fs.cd(dirname);
-                    }
-                    "ls" => {
-                        assert!(!listing, "Must not be listing already");
-                        listing = true;
-                    }
-                    _ => panic!("Unexpected command: {}", command),
-                }
-            }
-            "dir" => {
-                assert!(listing, "Found a directory while not listing");
-                let name = line.pop().expect("a dirname");
-                fs.mkdir(&name);
-            }
-            size if size.chars().all(|c| c.is_numeric()) => {
-                assert!(listing, "Found a file while not listing");
-                let size: usize = size.parse().unwrap();
-                let name = line.pop().expect("a file name");
-                fs.write(&name, size);
-            }
-            _ => panic!("unexpected input"),
+    for line in input {
+        match line {
+            TerminalLine::Cd(dirname) => fs.cd(dirname.clone()),
+            TerminalLine::Ls => {}
+            TerminalLine::Dir(name) => fs.mkdir(name),
+            TerminalLine::File { size, name } => fs.write(name, *size),
         }
     }
 
@@ -354,11 +311,13 @@ fn solve(input: Vec<Vec<String>>) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day07;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = parse("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day07::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 95437)
     }
 }
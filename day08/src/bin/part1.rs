@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day08::Day08;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day08>(Part::One)
+}
@@ -0,0 +1,50 @@
+use aoc_utils::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = parse::grid(input, |line, tree, _| {
+            tree.chars()
+                .find_map(|c| c.to_digit(10))
+                .ok_or_else(|| line.expected(tree, "a digit"))
+        })?;
+        if grid.is_empty() {
+            return Err(Line::new(1, "").expected("", "a map of the trees"));
+        }
+        Ok(grid)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day08::parse("30373\n25x12\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "x");
+
+        let err = Day08::parse("30373\n2551\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
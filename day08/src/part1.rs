@@ -1,27 +1,4 @@
-use std::{
-    fs::File,
-    io::{self, BufRead},
-};
-
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn parse(path: &str) -> io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let parsed: Vec<_> = io::BufReader::new(file).lines().flatten().collect();
-    Ok(parsed)
-}
-
-fn solve(input: Vec<String>) -> u64 {
-    let tree_map: Vec<Vec<_>> = input
-        .into_iter()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
-        .collect();
-
+pub fn solve(tree_map: &[Vec<u32>]) -> u64 {
     let mut results = vec![];
     for (j, line) in tree_map.iter().enumerate() {
         for (i, tree) in line.iter().enumerate() {
@@ -33,7 +10,7 @@ fn solve(input: Vec<String>) -> u64 {
                 Direction::Left,
             ]
             .into_iter()
-            .any(|d| visible_from_direction(&tree_map, j, i, d));
+            .any(|d| visible_from_direction(tree_map, j, i, d));
 
             if is_visible {
                 results.push((i, j, tree));
@@ -73,11 +50,13 @@ fn visible_from_direction(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day08;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = parse("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day08::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 21)
     }
 }
@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day09::Day09;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day09>(Part::Two)
+}
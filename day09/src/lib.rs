@@ -0,0 +1,68 @@
+use aoc_utils::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    /// Direction and number of steps of each motion of the head
+    type Input = Vec<(Direction, usize)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| {
+                let mut words = line.words();
+                let what = "`U`, `R`, `D` or `L`";
+                let direction = match words.word(what)? {
+                    "U" => Direction::Up,
+                    "R" => Direction::Right,
+                    "D" => Direction::Down,
+                    "L" => Direction::Left,
+                    word => return Err(line.expected(word, what)),
+                };
+                let count = words.number("the number of steps")?;
+                words.finish()?;
+                Ok((direction, count))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day09::parse("R 4\nX 4\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "X");
+
+        let err = Day09::parse("R -4\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert_eq!(err.text, "-4");
+    }
+}
@@ -1,36 +1,5 @@
+use crate::Direction;
 use std::collections::BTreeSet;
-use std::{
-    fs::File,
-    io::{self, BufRead},
-};
-
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn parse(path: &str) -> io::Result<Vec<Vec<String>>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-
-    let mut parsed = Vec::new();
-    for line in lines.into_iter().flatten() {
-        let words: Vec<String> = line.split_whitespace().map(String::from).collect();
-        parsed.push(words);
-    }
-
-    Ok(parsed)
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
 
 #[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct Position(i64, i64);
@@ -93,6 +62,7 @@ impl Rope {
         (0..count).for_each(|_| self.step(direction))
     }
 
+    #[allow(dead_code)]
     fn print_position(&mut self) {
         for j in (0..5).rev() {
             for i in 0..6 {
@@ -112,26 +82,9 @@ impl Rope {
     }
 }
 
-impl TryFrom<&str> for Direction {
-    type Error = String;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "R" => Ok(Direction::Right),
-            "U" => Ok(Direction::Up),
-            "D" => Ok(Direction::Down),
-            "L" => Ok(Direction::Left),
-            c => Err(format!("invalid character {}", c)),
-        }
-    }
-}
-
-fn solve(input: Vec<Vec<String>>) -> u64 {
+pub fn solve(input: &[(Direction, usize)]) -> u64 {
     let mut rope = Rope::default();
-    for mut line in input {
-        let count = line.pop().expect("a count").parse().expect("a valid count");
-        let dir_char = line.pop().expect("a direction");
-        let direction: Direction = (&dir_char[..]).try_into().expect("a valid character");
+    for &(direction, count) in input {
         rope.step_many(direction, count);
     }
 
@@ -141,11 +94,13 @@ fn solve(input: Vec<Vec<String>>) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day09;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = parse("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day09::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 13)
     }
 }
@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day10::Day10;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day10>(Part::One)
+}
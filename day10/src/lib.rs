@@ -0,0 +1,70 @@
+use aoc_utils::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Inst {
+    Noop,
+    Addx(i64),
+}
+
+impl Inst {
+    /// Number of cycles that the instruction takes to complete
+    pub fn ticks(&self) -> u8 {
+        match self {
+            Inst::Noop => 1,
+            Inst::Addx(_) => 2,
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Inst>;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| {
+                let mut words = line.words();
+                let inst = match words.word("an instruction")? {
+                    "noop" => Inst::Noop,
+                    "addx" => Inst::Addx(words.number("an immediate")?),
+                    inst => return Err(line.expected(inst, "`noop` or `addx`")),
+                };
+                words.finish()?;
+                Ok(inst)
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day10::parse("noop\naddx\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+
+        let err = Day10::parse("noop\nsubx 3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "subx");
+    }
+}
@@ -1,42 +1,4 @@
-use std::{
-    fs::File,
-    io::{self, BufRead},
-};
-
-#[derive(Debug, Copy, Clone)]
-enum Inst {
-    Noop,
-    Addx(i64),
-}
-
-impl Inst {
-    fn ticks(&self) -> u8 {
-        match self {
-            Inst::Noop => 1,
-            Inst::Addx(_) => 2,
-        }
-    }
-}
-
-impl TryFrom<&str> for Inst {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut parts = value.split_whitespace();
-        let inst = parts.next().ok_or("no instruction found")?;
-        match inst {
-            "noop" => Ok(Inst::Noop),
-            "addx" => {
-                let imm = parts.next().ok_or("no immediate found")?;
-                let imm = imm
-                    .parse()
-                    .map_err(|_| "immediate is not a valid integer")?;
-                Ok(Inst::Addx(imm))
-            }
-            _ => Err("found invalid command"),
-        }
-    }
-}
+use crate::Inst;
 
 #[derive(Debug, Clone, Default)]
 struct Cpu {
@@ -103,25 +65,8 @@ impl Cpu {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn parse(path: &str) -> io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines().flatten().collect();
-    Ok(lines)
-}
-
-fn solve(input: Vec<String>) -> i64 {
-    let inst = input
-        .into_iter()
-        .flat_map(|line| (&line[..]).try_into())
-        .collect();
-    let mut cpu = Cpu::from_instructions(inst);
+pub fn solve(input: &[Inst]) -> i64 {
+    let mut cpu = Cpu::from_instructions(input.to_vec());
 
     // NOTE(alvaro): We start with 19 since we want the value BEFORE executing
     // cycle 20, not after
@@ -140,11 +85,13 @@ fn solve(input: Vec<String>) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day10;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = parse("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day10::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 13140)
     }
 }
@@ -1,42 +1,4 @@
-use std::{
-    fs::File,
-    io::{self, BufRead},
-};
-
-#[derive(Debug, Copy, Clone)]
-enum Inst {
-    Noop,
-    Addx(i64),
-}
-
-impl Inst {
-    fn ticks(&self) -> u8 {
-        match self {
-            Inst::Noop => 1,
-            Inst::Addx(_) => 2,
-        }
-    }
-}
-
-impl TryFrom<&str> for Inst {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut parts = value.split_whitespace();
-        let inst = parts.next().ok_or("no instruction found")?;
-        match inst {
-            "noop" => Ok(Inst::Noop),
-            "addx" => {
-                let imm = parts.next().ok_or("no immediate found")?;
-                let imm = imm
-                    .parse()
-                    .map_err(|_| "immediate is not a valid integer")?;
-                Ok(Inst::Addx(imm))
-            }
-            _ => Err("found invalid command"),
-        }
-    }
-}
+use crate::Inst;
 
 #[derive(Debug, Clone, Default)]
 struct Cpu {
@@ -94,26 +56,10 @@ impl Cpu {
     }
 }
 
-fn main() -> io::Result<()> {
-    // let input = parse("sample.txt")?;
-    let input = parse("input.txt")?;
-    solve(input);
-    Ok(())
-}
-
-fn parse(path: &str) -> io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines().flatten().collect();
-    Ok(lines)
-}
-
-fn solve(input: Vec<String>) {
-    let inst = input
-        .into_iter()
-        .flat_map(|line| (&line[..]).try_into())
-        .collect();
-    let mut cpu = Cpu::from_instructions(inst);
+pub fn solve(input: &[Inst]) -> String {
+    let mut cpu = Cpu::from_instructions(input.to_vec());
 
+    let mut screen = String::new();
     let mut pos: i64 = 0;
     while !cpu.is_done() {
         // Check the character to draw
@@ -122,13 +68,39 @@ fn solve(input: Vec<String>) {
         } else {
             '.'
         };
-        print!("{}", ch);
+        screen.push(ch);
         pos += 1;
         if pos % 40 == 0 {
-            println!();
+            screen.push('\n');
             pos = 0;
         }
         // Tick the CPU
         cpu.tick();
     }
+    // Drop the newline after the last row
+    screen.truncate(screen.trim_end().len());
+    screen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use aoc_utils::Solution;
+
+    #[test]
+    fn test_sample() {
+        let input = Day10::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
+        let expected = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ]
+        .join("\n");
+        assert_eq!(solution, expected)
+    }
 }
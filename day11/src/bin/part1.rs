@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day11::Day11;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day11>(Part::One)
+}
@@ -0,0 +1,203 @@
+use aoc_utils::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+use std::collections::VecDeque;
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Value(u64),
+}
+
+impl Operand {
+    pub fn value(&self, old: u64) -> u64 {
+        match self {
+            Operand::Old => old,
+            Operand::Value(value) => *value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add(Operand, Operand),
+    Mul(Operand, Operand),
+}
+
+impl Operation {
+    pub fn apply(&self, old: u64) -> u64 {
+        match self {
+            Operation::Add(left, right) => left.value(old) + right.value(old),
+            Operation::Mul(left, right) => left.value(old) * right.value(old),
+        }
+    }
+}
+
+/// Notes about a monkey. Its number is its index in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    pub items: VecDeque<u64>,
+    pub operation: Operation,
+    pub test: u64,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+fn parse_operand(line: &Line, word: &str) -> Result<Operand, ParseError> {
+    match word {
+        "old" => Ok(Operand::Old),
+        value => Ok(Operand::Value(line.number(value)?)),
+    }
+}
+
+fn parse_operation(line: &Line) -> Result<Operation, ParseError> {
+    let mut words = line.words();
+    words.keyword("Operation:")?;
+    words.keyword("new")?;
+    words.keyword("=")?;
+    let left = parse_operand(line, words.word("an operand")?)?;
+    let operation = match words.word("an operator")? {
+        "+" => Operation::Add,
+        "*" => Operation::Mul,
+        op => return Err(line.expected(op, "`+` or `*`")),
+    };
+    let right = parse_operand(line, words.word("an operand")?)?;
+    words.finish()?;
+    Ok(operation(left, right))
+}
+
+/// Parse the line with the monkey that receives the item when the test is
+/// `condition`, out of the `count` monkeys
+fn parse_target(line: &Line, condition: &str, count: usize) -> Result<usize, ParseError> {
+    let mut words = line.words();
+    words.keyword("If")?;
+    words.keyword(&format!("{condition}:"))?;
+    words.keyword("throw")?;
+    words.keyword("to")?;
+    words.keyword("monkey")?;
+    let word = words.word("a monkey number")?;
+    let target = line.number(word)?;
+    words.finish()?;
+    if target >= count {
+        return Err(line.invalid(word, format!("there is no monkey {target}")));
+    }
+    Ok(target)
+}
+
+/// Parse the notes about the monkey `number`, out of the `count` monkeys
+fn parse_monkey(notes: &[Line], number: usize, count: usize) -> Result<Monkey, ParseError> {
+    let last = notes[notes.len() - 1];
+    let note = |i: usize, what: &str| {
+        notes
+            .get(i)
+            .ok_or_else(|| last.expected(last.end(), format!("a line with `{what}`")))
+    };
+
+    let line = note(0, "Monkey")?;
+    let mut words = line.words();
+    words.keyword("Monkey")?;
+    let word = words.word("the monkey number")?;
+    let expected = format!("`{number}:`");
+    if word.strip_suffix(':').and_then(|n| n.parse().ok()) != Some(number) {
+        return Err(line.expected(word, expected));
+    }
+    words.finish()?;
+
+    let line = note(1, "Starting items:")?;
+    let items = line.after("Starting items:")?;
+    let items = if items.trim().is_empty() {
+        VecDeque::new()
+    } else {
+        items
+            .split(',')
+            .map(|item| line.number(item.trim()))
+            .collect::<Result<_, _>>()?
+    };
+
+    let operation = parse_operation(note(2, "Operation:")?)?;
+
+    let line = note(3, "Test:")?;
+    let mut words = line.words();
+    words.keyword("Test:")?;
+    words.keyword("divisible")?;
+    words.keyword("by")?;
+    let test = words.number("a divisor")?;
+    words.finish()?;
+
+    let if_true = parse_target(note(4, "If true:")?, "true", count)?;
+    let if_false = parse_target(note(5, "If false:")?, "false", count)?;
+    if let Some(line) = notes.get(6) {
+        return Err(line.expected(line.text.trim(), "a blank line"));
+    }
+    Ok(Monkey {
+        items,
+        operation,
+        test,
+        if_true,
+        if_false,
+    })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let groups = parse::groups(input);
+        groups
+            .iter()
+            .enumerate()
+            .map(|(i, notes)| parse_monkey(notes, i, groups.len()))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let monkeys = Day11::parse(include_str!("../sample.txt")).unwrap();
+        assert_eq!(monkeys.len(), 4);
+        assert_eq!(
+            monkeys[0],
+            Monkey {
+                items: VecDeque::from([79, 98]),
+                operation: Operation::Mul(Operand::Old, Operand::Value(19)),
+                test: 23,
+                if_true: 2,
+                if_false: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let sample = include_str!("../sample.txt");
+        let err = Day11::parse(&sample.replace("old * 19", "old / 19")).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 24));
+        assert_eq!(err.text, "/");
+
+        let err = Day11::parse(&sample.replace("monkey 3", "monkey 4")).unwrap_err();
+        assert_eq!((err.line, err.column), (6, 31));
+
+        let err = Day11::parse(&sample.replace("Monkey 1:", "Monkey 2:")).unwrap_err();
+        assert_eq!((err.line, err.column), (8, 8));
+    }
+}
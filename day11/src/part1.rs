@@ -1,184 +1,7 @@
-use regex::Regex;
-use std::{
-    collections::VecDeque,
-    fs::File,
-    io::{self, BufRead},
-    str::FromStr,
-};
+use crate::Monkey;
 
-// NOTE(alvaro): It would be nice to try to use `nom` (parser combinators)
-// to parse this input
-#[derive(Debug)]
-enum Operand {
-    Old,
-    Value(i64),
-}
-
-impl Operand {
-    fn value(&self, old: i64) -> i64 {
-        match self {
-            Operand::Old => old,
-            Operand::Value(value) => *value,
-        }
-    }
-}
-
-impl FromStr for Operand {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "old" => Ok(Operand::Old),
-            num if num.chars().all(|c| c.is_numeric()) => Ok(Operand::Value(num.parse().unwrap())),
-            _ => Err("Invalid operand"),
-        }
-    }
-}
-
-#[derive(Debug)]
-enum Operation {
-    Add(Operand, Operand),
-    Mul(Operand, Operand),
-}
-
-impl Operation {
-    fn apply(&self, old: i64) -> i64 {
-        match self {
-            Operation::Add(left, right) => left.value(old) + right.value(old),
-            Operation::Mul(left, right) => left.value(old) * right.value(old),
-        }
-    }
-}
-
-fn main() -> io::Result<()> {
-    let input = parse("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn parse(path: &str) -> io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-    Ok(lines.into_iter().flatten().collect())
-}
-
-// NOTE(alvaro): We could use `lazy_static` to make these global constants
-fn parse_monkey_line(line: &str) -> usize {
-    let re = Regex::new(r"^\s*Monkey (\d+):\s*$").unwrap();
-    let cap = re.captures_iter(line).next().expect("monkey line to match");
-    cap[1].parse().unwrap()
-}
-
-fn parse_items_line(line: &str) -> VecDeque<i64> {
-    let items_start: usize = line.find(':').expect("line to have :") + 1;
-    let line = &line[items_start..];
-    let item_re = Regex::new(r"\s*(\d+)\s*,?").unwrap();
-    item_re
-        .captures_iter(line)
-        .map(|cap| cap[1].parse().unwrap())
-        .collect()
-}
-
-fn parse_operation_line(line: &str) -> Operation {
-    let re = Regex::new(r"^\s*Operation:\s*new\s*=\s*(old|\d+)\s*(\+|\*)\s*(old|\d+)\s*$").unwrap();
-    let cap = re.captures_iter(line).next().unwrap();
-    let (left, op, right) = (&cap[1], &cap[2], &cap[3]);
-    let left = left.parse().unwrap();
-    let right = right.parse().unwrap();
-    match op {
-        "+" => Operation::Add(left, right),
-        "*" => Operation::Mul(left, right),
-        _ => unreachable!(),
-    }
-}
-
-fn parse_test_lines<'a>(line: impl IntoIterator<Item = &'a str>) -> (i64, usize, usize) {
-    // Parse the first test line
-    let re = Regex::new(r"^\s*Test:\s*divisible\s+by\s+(\d+)\s*$").unwrap();
-    let mut line_iter = line.into_iter();
-    let first_line = line_iter.next().unwrap();
-    let test_cap = re
-        .captures_iter(first_line)
-        .next()
-        .expect("monkey line to match");
-    let test_value = test_cap[1].parse().unwrap();
-    // Parse true / flase
-    let true_false_re =
-        Regex::new(r"^\s*If\s+(?:true|false)\s*:\s*throw\s+to\s+monkey\s+(\d+)\s*$").unwrap();
-    let true_line = line_iter.next().unwrap();
-    let true_monkey = true_false_re
-        .captures_iter(true_line)
-        .next()
-        .expect("true line to match")[1]
-        .parse()
-        .unwrap();
-    let false_line = line_iter.next().unwrap();
-    let false_monkey = true_false_re
-        .captures_iter(false_line)
-        .next()
-        .expect("false line to match")[1]
-        .parse()
-        .unwrap();
-    (test_value, true_monkey, false_monkey)
-}
-
-#[derive(Debug)]
-struct MonkeyInstructions {
-    number: usize,
-    items: VecDeque<i64>,
-    operation: Operation,
-    test: i64,
-    if_true: usize,
-    if_false: usize,
-}
-
-impl MonkeyInstructions {
-    fn new(
-        number: usize,
-        items: VecDeque<i64>,
-        operation: Operation,
-        test: i64,
-        if_true: usize,
-        if_false: usize,
-    ) -> Self {
-        Self {
-            number,
-            items,
-            operation,
-            test,
-            if_true,
-            if_false,
-        }
-    }
-}
-
-fn solve(input: Vec<String>) -> u64 {
-    // TODO(alvaro): Can we use `group_by()` for this?
-    // Prepare the text to be parsed
-    let mut instructions = vec![vec![]];
-    for line in input.into_iter() {
-        let line = line.trim_start().to_owned();
-        if line.is_empty() {
-            instructions.push(Vec::new());
-        } else {
-            instructions.last_mut().unwrap().push(line);
-        }
-    }
-
-    let mut monkeys = vec![];
-    // Parse the Monkey instructions
-    for turn in instructions {
-        let mut turn_iter = turn.iter().map(|s| &s[..]);
-        // Parse the monkey line
-        let monkey_n = parse_monkey_line(turn_iter.next().unwrap());
-        let items = parse_items_line(turn_iter.next().unwrap());
-        let operation = parse_operation_line(turn_iter.next().unwrap());
-        let (test, if_true, if_false) = parse_test_lines(&mut turn_iter);
-        monkeys.push(MonkeyInstructions::new(
-            monkey_n, items, operation, test, if_true, if_false,
-        ));
-    }
+pub fn solve(input: &[Monkey]) -> u64 {
+    let mut monkeys = input.to_vec();
 
     // A temporary insertion vector used to please the borrow checker
     let mut insertions = Vec::new();
@@ -192,7 +15,7 @@ fn solve(input: Vec<String>) -> u64 {
             while let Some(item) = monkey.items.pop_front() {
                 let new_value = monkey.operation.apply(item);
                 let new_value = new_value / 3; // NOTE: Integer division
-                let target_monkey_n = if new_value % monkey.test == 0 {
+                let target_monkey_n = if new_value.is_multiple_of(monkey.test) {
                     monkey.if_true
                 } else {
                     monkey.if_false
@@ -210,10 +33,7 @@ fn solve(input: Vec<String>) -> u64 {
 
             // Actually perform the insertions, draining the vector
             for (item, to) in insertions.drain(..) {
-                let target_monkey = monkeys
-                    .get_mut(to)
-                    .expect("the target monkey number to exist");
-                target_monkey.items.push_back(item);
+                monkeys[to].items.push_back(item);
             }
         }
     }
@@ -226,11 +46,13 @@ fn solve(input: Vec<String>) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day11;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = parse("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day11::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 10605)
     }
 }
@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day12::Day12;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day12>(Part::One)
+}
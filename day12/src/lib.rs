@@ -0,0 +1,81 @@
+use aoc_utils::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// Column and row of a square of the heightmap
+pub type Position = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heightmap {
+    /// Height of each square by row, from 0 (`a`) to 25 (`z`)
+    pub heights: Vec<Vec<u8>>,
+    pub start: Position,
+    pub end: Position,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut start = None;
+        let mut end = None;
+        let heights = parse::grid(input, |line, square, position| {
+            let (marker, height) = match square.as_bytes() {
+                b"S" => (&mut start, 0),
+                b"E" => (&mut end, b'z' - b'a'),
+                &[c @ b'a'..=b'z'] => return Ok(c - b'a'),
+                _ => return Err(line.expected(square, "a height (`a`-`z`), `S` or `E`")),
+            };
+            if marker.is_some() {
+                return Err(line.invalid(square, format!("there is more than one `{square}`")));
+            }
+            *marker = Some(position);
+            Ok(height)
+        })?;
+        let last = parse::lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .last()
+            .unwrap_or(Line::new(1, ""));
+        let start = start.ok_or_else(|| last.expected(last.end(), "the start position `S`"))?;
+        let end = end.ok_or_else(|| last.expected(last.end(), "the best signal position `E`"))?;
+        Ok(Heightmap {
+            heights,
+            start,
+            end,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day12::parse("Sabc\nab1E\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "1");
+
+        let err = Day12::parse("Sabc\nabSE\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = Day12::parse("Sabc\nabcd\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+}
@@ -1,37 +1,6 @@
+use crate::{Heightmap, Position};
 use itertools::Itertools;
-use std::{
-    collections::{BinaryHeap, HashMap},
-    fs::File,
-    io::{self, BufRead},
-};
-
-fn main() -> io::Result<()> {
-    let input = lines("input.txt")?;
-    let solution = solve(input);
-    println!("{solution}");
-    Ok(())
-}
-
-fn lines(path: &str) -> io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-    Ok(lines.into_iter().flatten().collect())
-}
-
-fn _line_words(path: &str) -> io::Result<Vec<Vec<String>>> {
-    let file = File::open(path)?;
-    let lines = io::BufReader::new(file).lines();
-
-    let mut parsed = Vec::new();
-    for line in lines.into_iter().flatten() {
-        let words: Vec<String> = line.split_whitespace().map(String::from).collect();
-        parsed.push(words);
-    }
-
-    Ok(parsed)
-}
-
-type Position = (usize, usize);
+use std::collections::{BinaryHeap, HashMap};
 
 #[derive(Debug, PartialEq, Eq)]
 struct ShortestPath {
@@ -67,6 +36,7 @@ impl ShortestPath {
 #[derive(Debug)]
 struct Map {
     heights: Vec<Vec<u8>>,
+    #[allow(dead_code)]
     start: Position,
     end: Position,
 }
@@ -80,34 +50,8 @@ impl Map {
         }
     }
 
-    fn from_input(input: Vec<String>) -> Self {
-        let mut start = (0, 0);
-        let mut end = (0, 0);
-
-        let heights: Vec<Vec<u8>> = input
-            .iter()
-            .enumerate()
-            .map(|(j, l)| {
-                l.chars()
-                    .into_iter()
-                    .enumerate()
-                    .map(|(i, c)| match c {
-                        'S' => {
-                            start = (i, j);
-                            0
-                        }
-                        'E' => {
-                            end = (i, j);
-                            b'z' - b'a'
-                        }
-                        c @ 'a'..='z' => c as u8 - b'a',
-                        _ => panic!("Invalid character found"),
-                    })
-                    .collect()
-            })
-            .collect();
-
-        Self::new(heights, start, end)
+    fn from_input(input: &Heightmap) -> Self {
+        Self::new(input.heights.clone(), input.start, input.end)
     }
 
     fn hill_climb(&self, start: Position) -> u64 {
@@ -193,7 +137,7 @@ impl Map {
     }
 }
 
-fn solve(input: Vec<String>) -> u64 {
+pub fn solve(input: &Heightmap) -> u64 {
     let map = Map::from_input(input);
     map.heights
         .iter()
@@ -209,11 +153,13 @@ fn solve(input: Vec<String>) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day12;
+    use aoc_utils::Solution;
 
     #[test]
     fn test_sample() {
-        let input = lines("sample.txt").unwrap();
-        let solution = solve(input);
+        let input = Day12::parse(include_str!("../sample.txt")).unwrap();
+        let solution = solve(&input);
         assert_eq!(solution, 29)
     }
 }
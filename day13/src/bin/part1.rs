@@ -0,0 +1,7 @@
+use aoc_utils::Part;
+use day13::Day13;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::main::<Day13>(Part::One)
+}
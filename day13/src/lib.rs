@@ -0,0 +1,229 @@
+use aoc_utils::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+use itertools::{EitherOrBoth, Itertools};
+use nom::{
+    branch::alt,
+    combinator::{cut, map, opt},
+    multi::many0,
+    sequence::{delimited, preceded},
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+// NOTE: Taking the chance to learn how to use `nom` and parser combinators
+// with a simple example
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketValue {
+    Value(i32),
+    List(Vec<PacketValue>),
+}
+
+impl Ord for PacketValue {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (PacketValue::Value(left), PacketValue::Value(right)) => left.cmp(right),
+            (PacketValue::List(left), PacketValue::List(right)) => {
+                // Compare lists
+                for pair in left.iter().zip_longest(right.iter()) {
+                    match pair {
+                        EitherOrBoth::Both(l, r) => match l.cmp(r) {
+                            std::cmp::Ordering::Equal => continue,
+                            cmp => return cmp,
+                        },
+                        EitherOrBoth::Left(..) => return std::cmp::Ordering::Greater,
+                        EitherOrBoth::Right(..) => return std::cmp::Ordering::Less,
+                    }
+                }
+                std::cmp::Ordering::Equal
+            }
+            (left @ PacketValue::List(..), PacketValue::Value(right)) => {
+                left.cmp(&PacketValue::List(vec![PacketValue::Value(*right)]))
+            }
+            (PacketValue::Value(left), right @ PacketValue::List(..)) => {
+                let left = PacketValue::List(vec![PacketValue::Value(*left)]);
+                left.cmp(right)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for PacketValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PacketValue::List(vec) => {
+                write!(f, "[")?;
+                for (i, e) in vec.iter().enumerate() {
+                    e.fmt(f)?;
+                    if i < vec.len() - 1 {
+                        write!(f, ",")?;
+                    }
+                }
+                write!(f, "]")?;
+            }
+            PacketValue::Value(value) => {
+                write!(f, "{}", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PartialOrd for PacketValue {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn value(input: &str) -> IResult<&str, PacketValue> {
+    map(nom::character::complete::i32, PacketValue::Value)(input)
+}
+
+/// Parser for the comma separated elements of a list. Once a comma is found
+/// an element must follow, so that errors point at the offending element
+fn elements(input: &str) -> IResult<&str, Vec<PacketValue>> {
+    let (input, first) = opt(alt((value, list)))(input)?;
+    let Some(first) = first else {
+        return Ok((input, vec![]));
+    };
+    let (input, rest) = many0(preceded(
+        nom::character::complete::char(','),
+        cut(alt((value, list))),
+    ))(input)?;
+    Ok((input, std::iter::once(first).chain(rest).collect()))
+}
+
+/// Parser for a PacketValue::List
+fn list(input: &str) -> IResult<&str, PacketValue> {
+    map(
+        delimited(
+            nom::character::complete::char('['),
+            elements,
+            nom::character::complete::char(']'),
+        ),
+        PacketValue::List,
+    )(input)
+}
+
+fn parse_packet(line: &Line) -> Result<PacketValue, ParseError> {
+    let text = line.text.trim();
+    match list(text) {
+        Ok(("", packet)) => Ok(packet),
+        Ok((rest, _)) => Err(line.expected(rest, "the end of the packet")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            let found = e.input.chars().next().map_or(0, char::len_utf8);
+            Err(line.expected(&e.input[..found], "a list or a number"))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(line.expected(line.end(), "a list or a number")),
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    /// Pairs of packets, in order
+    type Input = Vec<(PacketValue, PacketValue)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::groups(input)
+            .into_iter()
+            .map(|pair| match pair[..] {
+                [left, right] => Ok((parse_packet(&left)?, parse_packet(&right)?)),
+                [left] => Err(left.expected(left.end(), "a second packet in the next line")),
+                [_, _, extra, ..] => Err(extra.expected(extra.text, "a blank line")),
+                [] => unreachable!("groups are never empty"),
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(value("32"), Ok(("", PacketValue::Value(32))));
+        assert_eq!(value("-32"), Ok(("", PacketValue::Value(-32))));
+    }
+
+    #[test]
+    fn test_parse_empty_list() {
+        assert_eq!(list("[]"), Ok(("", PacketValue::List(vec![]))));
+    }
+
+    #[test]
+    fn test_parse_flat_list() {
+        assert_eq!(
+            list("[1,2,-3,4]"),
+            Ok((
+                "",
+                PacketValue::List(vec![
+                    PacketValue::Value(1),
+                    PacketValue::Value(2),
+                    PacketValue::Value(-3),
+                    PacketValue::Value(4),
+                ])
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_deep_list() {
+        assert_eq!(
+            list("[1,2,[-3],4]"),
+            Ok((
+                "",
+                PacketValue::List(vec![
+                    PacketValue::Value(1),
+                    PacketValue::Value(2),
+                    PacketValue::List(vec![PacketValue::Value(-3)]),
+                    PacketValue::Value(4),
+                ])
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_deeper_list() {
+        assert_eq!(
+            list("[1,2,[[-3]],4]"),
+            Ok((
+                "",
+                PacketValue::List(vec![
+                    PacketValue::Value(1),
+                    PacketValue::Value(2),
+                    PacketValue::List(vec![PacketValue::List(vec![PacketValue::Value(-3)])]),
+                    PacketValue::Value(4),
+                ])
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day13::parse("[1,1,3]\n[1,1,x]\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.text, "x");
+
+        let err = Day13::parse("[1,1,3]\n[1,1]]\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+
+        let err = Day13::parse("[1]\n[2]\n[3]\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
@@ -0,0 +1,131 @@
+use aoc_utils::Part;
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+/// Known-correct answers for the inputs of each day, stored as a TOML file
+/// with one table per day and one key per part:
+///
+/// ```toml
+/// [day01]
+/// part1 = "24000"
+/// part2 = "45000"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Answers {
+    /// Load the answers stored at `path`. A missing file has no answers
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+        };
+        let days = toml::from_str(&contents).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        })?;
+        Ok(Self { days })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = toml::to_string(&self.days)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.days
+            .get(&day_key(day))
+            .and_then(|parts| parts.get(&part_key(part)))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: impl Into<String>) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .insert(part_key(part), answer.into());
+    }
+
+    /// Compare an `answer` obtained for `day` and `part` with the known one
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Changed {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+fn part_key(part: Part) -> String {
+    format!("part{part}")
+}
+
+/// Result of checking an answer against the known answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer is different from the known one
+    Changed {
+        expected: String,
+    },
+    /// There is no known answer to compare with
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "ok"),
+            Verdict::Changed { .. } => write!(f, "CHANGED"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, "24000");
+
+        assert_eq!(answers.check(1, Part::One, "24000"), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, Part::One, "45000"),
+            Verdict::Changed {
+                expected: "24000".into()
+            }
+        );
+        assert_eq!(answers.check(1, Part::Two, "45000"), Verdict::Unknown);
+        assert_eq!(answers.check(2, Part::One, "15"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, "24000");
+        answers.insert(10, Part::Two, "##..\n..##");
+        answers.save(&path).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("[day01]\npart1 = \"24000\"\n"));
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+    }
+}
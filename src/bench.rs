@@ -0,0 +1,204 @@
+use aoc_utils::{Part, Solution};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    hint::black_box,
+    io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Timings of several runs of the same code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Run `f` the given number of `iterations` (at least once) and time it
+    pub fn measure<T>(iterations: u32, mut f: impl FnMut() -> T) -> Self {
+        let iterations = iterations.max(1);
+        let mut total = Duration::ZERO;
+        let mut min = Duration::MAX;
+        let mut max = Duration::ZERO;
+        for _ in 0..iterations {
+            let start = Instant::now();
+            black_box(f());
+            let elapsed = start.elapsed();
+            total += elapsed;
+            min = min.min(elapsed);
+            max = max.max(elapsed);
+        }
+        Self {
+            mean: total / iterations,
+            min,
+            max,
+        }
+    }
+}
+
+/// Time the parsing of `input` and each part of the solution separately
+pub fn bench<S: Solution>(input: &str, iterations: u32) -> io::Result<Vec<(String, Stats)>> {
+    let mut timings = vec![(
+        "parse".to_string(),
+        Stats::measure(iterations, || S::parse(black_box(input))),
+    )];
+    let input = S::parse(input)?;
+    for part in Part::ALL {
+        let stats = Stats::measure(iterations, || S::solve(black_box(&input), part));
+        timings.push((format!("part{part}"), stats));
+    }
+    Ok(timings)
+}
+
+/// Mean timings of a previous run to compare with, stored as a TOML file
+/// with one table per day and the mean of each phase in nanoseconds:
+///
+/// ```toml
+/// [day01]
+/// parse = 102500
+/// part1 = 12100
+/// part2 = 15800
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    days: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        let days = toml::from_str(&contents).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        })?;
+        Ok(Self { days })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = toml::to_string(&self.days)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, day: u8, phase: &str) -> Option<Duration> {
+        self.days
+            .get(&format!("day{day:02}"))
+            .and_then(|phases| phases.get(phase))
+            .map(|&nanos| Duration::from_nanos(nanos))
+    }
+
+    pub fn insert(&mut self, day: u8, phase: &str, mean: Duration) {
+        self.days
+            .entry(format!("day{day:02}"))
+            .or_default()
+            .insert(phase.to_string(), mean.as_nanos() as u64);
+    }
+}
+
+/// Render the `timings` of each day as a table, comparing the means with
+/// the ones of the `baseline` (if any)
+pub fn table(timings: &[(u8, Vec<(String, Stats)>)], baseline: Option<&Baseline>) -> String {
+    let mut table = format!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "day", "phase", "mean", "min", "max"
+    );
+    if baseline.is_some() {
+        write!(table, "  {:>10}  {:>8}", "baseline", "change").unwrap();
+    }
+    table.push('\n');
+    for (day, phases) in timings {
+        for (phase, stats) in phases {
+            write!(
+                table,
+                "{day:>3}  {phase:<5}  {:>10}  {:>10}  {:>10}",
+                format!("{:.1?}", stats.mean),
+                format!("{:.1?}", stats.min),
+                format!("{:.1?}", stats.max),
+            )
+            .unwrap();
+            match baseline.map(|b| b.get(*day, phase)) {
+                Some(Some(previous)) => {
+                    let change = (stats.mean.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+                    write!(
+                        table,
+                        "  {:>10}  {:>+7.1}%",
+                        format!("{previous:.1?}"),
+                        change
+                    )
+                    .unwrap();
+                }
+                Some(None) => write!(table, "  {:>10}  {:>8}", "-", "-").unwrap(),
+                None => {}
+            }
+            table.push('\n');
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(micros: u64) -> Stats {
+        let d = Duration::from_micros(micros);
+        Stats {
+            mean: d,
+            min: d,
+            max: d,
+        }
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let stats = Stats::measure(5, || calls += 1);
+        assert_eq!(calls, 5);
+        assert!(stats.min <= stats.mean && stats.mean <= stats.max);
+
+        // It always runs at least once
+        Stats::measure(0, || calls += 1);
+        assert_eq!(calls, 6);
+    }
+
+    #[test]
+    fn test_table() {
+        let timings = vec![(
+            1,
+            vec![
+                ("parse".to_string(), stats(150)),
+                ("part1".to_string(), stats(20)),
+            ],
+        )];
+        let mut baseline = Baseline::default();
+        baseline.insert(1, "parse", Duration::from_micros(100));
+
+        let table = table(&timings, Some(&baseline));
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].ends_with("baseline    change"));
+        assert!(lines[1].ends_with("100.0µs    +50.0%"));
+        assert!(lines[2].ends_with("-         -"));
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}.toml", std::process::id()));
+        let mut baseline = Baseline::default();
+        baseline.insert(11, "part2", Duration::from_micros(2500));
+        baseline.save(&path).unwrap();
+
+        let loaded = Baseline::load(&path).unwrap();
+        assert_eq!(loaded.get(11, "part2"), Some(Duration::from_micros(2500)));
+        assert_eq!(loaded.get(11, "part1"), None);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
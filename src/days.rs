@@ -0,0 +1,69 @@
+use crate::{
+    bench::{self, Stats},
+    summary::{self, Row},
+};
+use aoc_utils::{Part, Solution};
+use std::io;
+
+/// Generate the dispatch table from a day number to its `Solution`
+macro_rules! days {
+    ($($day:literal => $solution:path),* $(,)?) => {
+        /// Days that have a registered solution
+        pub const DAYS: &[u8] = &[$($day),*];
+
+        /// Run the solution of the given `day` for each of the `parts`
+        /// against the raw `input`, returning the rendered answers
+        pub fn solve(day: u8, parts: &[Part], input: &str) -> io::Result<Vec<String>> {
+            match day {
+                $($day => run::<$solution>(parts, input),)*
+                _ => Err(not_found(day)),
+            }
+        }
+
+        /// Time the parsing of the `input` and each part of the solution of
+        /// the given `day`
+        pub fn bench(day: u8, input: &str, iterations: u32) -> io::Result<Vec<(String, Stats)>> {
+            match day {
+                $($day => bench::bench::<$solution>(input, iterations),)*
+                _ => Err(not_found(day)),
+            }
+        }
+
+        /// Parse the `input` of the given `day` and solve both parts, timing
+        /// them and catching their failures
+        pub fn summary(day: u8, input: &str) -> io::Result<Row> {
+            match day {
+                $($day => Ok(summary::run::<$solution>(day, input)),)*
+                _ => Err(not_found(day)),
+            }
+        }
+    };
+}
+
+fn not_found(day: u8) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("there is no solution for day {day}"),
+    )
+}
+
+fn run<S: Solution>(parts: &[Part], input: &str) -> io::Result<Vec<String>> {
+    let input = S::parse(input)?;
+    Ok(parts.iter().map(|part| S::solve(&input, *part)).collect())
+}
+
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+}
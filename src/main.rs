@@ -1,3 +1,267 @@
-fn main() {
-    println!("Hello, world!");
+use answers::{Answers, Verdict};
+use aoc_utils::{
+    cache::{CacheArgs, InputCache},
+    input::InputArgs,
+    Part,
+};
+use bench::Baseline;
+use clap::{Parser, Subcommand};
+use std::{
+    io, panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+use summary::{Cell, Row};
+
+mod answers;
+mod bench;
+mod days;
+mod scaffold;
+mod summary;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solution for a given day, or every day with `--all`
+    Run {
+        /// Day to run
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Run every day and print a summary table of the answers and timings
+        #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+        all: bool,
+        /// Part to run (runs both parts if not given)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+    /// Create the crate for a new day from the `day00` template
+    New {
+        /// Day to create
+        day: u8,
+    },
+    /// Run every day against its `input.txt` and check that the answers
+    /// match the known ones
+    Verify {
+        /// File with the known answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Store the answers that are not known yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time the parsing and each part of the given days against their
+    /// `input.txt`
+    Bench {
+        /// Days to time (all of them if not given)
+        days: Vec<u8>,
+        /// Number of times each phase is run
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: u32,
+        /// Compare the timings with the ones saved in this file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Save the timings to this file, to be used as a baseline later
+        #[arg(long)]
+        save: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run {
+            all: true,
+            input,
+            cache,
+            ..
+        } => run_all(&input, &cache),
+        Command::Run {
+            day,
+            part,
+            input,
+            cache,
+            ..
+        } => run(day.expect("required by clap"), part, &input, &cache),
+        Command::New { day } => {
+            scaffold::new_day(Path::new("."), day).map(|dir| println!("Created {}", dir.display()))
+        }
+        Command::Verify { answers, record } => verify(&answers, record),
+        Command::Bench {
+            days,
+            iterations,
+            baseline,
+            save,
+        } => bench(days, iterations, baseline.as_deref(), save.as_deref()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: &InputArgs, cache: &CacheArgs) -> io::Result<()> {
+    if !days::DAYS.contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("day {day} is not solved yet"),
+        ));
+    }
+    let input = read_input(day, input, cache.cache()?.as_ref())?;
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part).expect("validated by clap")],
+        None => Part::ALL.to_vec(),
+    };
+    let solutions = days::solve(day, &parts, &input)?;
+    for (part, solution) in parts.iter().zip(solutions) {
+        println!("Day {day} part {part}: {solution}");
+    }
+    Ok(())
+}
+
+/// Read the input of `day` selected in the command line
+fn read_input(day: u8, input: &InputArgs, cache: Option<&InputCache>) -> io::Result<String> {
+    // An explicitly selected input takes precedence over the session cache
+    match cache {
+        Some(cache) if input.is_default() => cache.get(day),
+        _ => input.source(format!("day{day:02}").as_ref()).read(),
+    }
+}
+
+fn run_all(input: &InputArgs, cache: &CacheArgs) -> io::Result<()> {
+    let cache = cache.cache()?;
+    // The panics are reported in the summary instead
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let rows = days::DAYS
+        .iter()
+        .map(|&day| match read_input(day, input, cache.as_ref()) {
+            Ok(input) => days::summary(day, &input),
+            Err(e) => Ok(Row::missing_input(day, e.to_string())),
+        })
+        .collect::<io::Result<Vec<_>>>();
+    panic::set_hook(hook);
+    let rows = rows?;
+
+    print!("{}", summary::table(&rows));
+    let failed = rows.iter().filter(|row| row.failed()).count();
+    if failed > 0 {
+        return Err(io::Error::other(format!("{failed} days failed")));
+    }
+    Ok(())
+}
+
+fn verify(path: &Path, record: bool) -> io::Result<()> {
+    let mut answers = Answers::load(path)?;
+    let mut changed = 0;
+    let mut failed = 0;
+    let mut recorded = 0;
+    for &day in days::DAYS {
+        let input = match day_input(day) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {day}: skipped ({e})");
+                continue;
+            }
+        };
+        // A day that fails is reported and the others are still verified
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let row = days::summary(day, &input);
+        panic::set_hook(hook);
+        let row = row?;
+        for (part, cell) in Part::ALL.into_iter().zip(row.parts) {
+            let solution = match cell {
+                Cell::Done { answer, .. } => answer,
+                cell => {
+                    let failure = row.parse.failure().or_else(|| cell.failure());
+                    println!("Day {day} part {part}: {}", failure.unwrap_or_default());
+                    failed += 1;
+                    continue;
+                }
+            };
+            let verdict = answers.check(day, part, &solution);
+            println!("Day {day} part {part}: {verdict}");
+            match verdict {
+                Verdict::Correct => {}
+                Verdict::Changed { expected } => {
+                    println!("expected:\n{expected}\ngot:\n{solution}");
+                    changed += 1;
+                }
+                Verdict::Unknown if record => {
+                    answers.insert(day, part, solution);
+                    recorded += 1;
+                }
+                Verdict::Unknown => {}
+            }
+        }
+    }
+    if recorded > 0 {
+        answers.save(path)?;
+        println!("Recorded {recorded} new answers in {}", path.display());
+    }
+    let problems: Vec<_> = [(changed, "changed"), (failed, "failed")]
+        .into_iter()
+        .filter(|&(count, _)| count > 0)
+        .map(|(count, what)| format!("{count} answers {what}"))
+        .collect();
+    if !problems.is_empty() {
+        return Err(io::Error::other(problems.join(", ")));
+    }
+    Ok(())
+}
+
+fn bench(
+    days: Vec<u8>,
+    iterations: u32,
+    baseline: Option<&Path>,
+    save: Option<&Path>,
+) -> io::Result<()> {
+    let baseline = baseline.map(Baseline::load).transpose()?;
+    let days = if days.is_empty() {
+        days::DAYS.to_vec()
+    } else {
+        days
+    };
+    let mut timings = Vec::new();
+    for day in days {
+        let input = match day_input(day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {day}: skipped ({e})");
+                continue;
+            }
+        };
+        timings.push((day, days::bench(day, &input, iterations)?));
+    }
+    print!("{}", bench::table(&timings, baseline.as_ref()));
+    if let Some(path) = save {
+        let mut baseline = Baseline::default();
+        for (day, phases) in &timings {
+            for (phase, stats) in phases {
+                baseline.insert(*day, phase, stats.mean);
+            }
+        }
+        baseline.save(path)?;
+    }
+    Ok(())
+}
+
+/// Read the `input.txt` of `day`
+fn day_input(day: u8) -> io::Result<String> {
+    InputArgs::default()
+        .source(format!("day{day:02}").as_ref())
+        .read()
 }
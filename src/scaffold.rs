@@ -0,0 +1,185 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Name of the crate that is copied to create the new days
+const TEMPLATE: &str = "day00";
+
+/// Attribute of the placeholder tests of the template, which are meant to
+/// fail until the solution is written. It is removed from the new days, so
+/// that only the template itself does not break the workspace tests
+const PLACEHOLDER: &str = "#[ignore = \"placeholder of the template\"]";
+
+/// Create the crate for `day` from the `day00` template inside the workspace
+/// at `root`, and register it in the workspace and in the runner. Returns
+/// the directory of the new crate
+pub fn new_day(root: &Path, day: u8) -> io::Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid day {day}, it must be in 1..=25"),
+        ));
+    }
+    let name = format!("day{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    // Read everything that is going to be modified before touching anything
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let days_path = root.join("src").join("days.rs");
+    let days = fs::read_to_string(&days_path)?;
+
+    let manifest = append_line(&manifest, "members = [", "]", &format!("    \"{name}\","))?;
+    let manifest = append_line(
+        &manifest,
+        "[dependencies]",
+        "",
+        &format!("{name} = {{ path = \"{name}\" }}"),
+    )?;
+    let days = append_line(
+        &days,
+        "days! {",
+        "}",
+        &format!("    {day} => {name}::Day{day:02},"),
+    )?;
+
+    copy_template(&root.join(TEMPLATE), &dir, &name, &format!("Day{day:02}"))?;
+    for file in ["input.txt", "sample.txt"] {
+        let path = dir.join(file);
+        if !path.exists() {
+            fs::write(path, "")?;
+        }
+    }
+    fs::write(manifest_path, manifest)?;
+    fs::write(days_path, days)?;
+    Ok(dir)
+}
+
+/// Recursively copy the template crate at `from` into `to`, renaming the
+/// crate and its `Solution` type
+fn copy_template(from: &Path, to: &Path, name: &str, solution: &str) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != "target" {
+                copy_template(&path, &target, name, solution)?;
+            }
+        } else {
+            let contents = fs::read_to_string(&path)?;
+            let contents = contents
+                .replace(TEMPLATE, name)
+                .replace("Day00", solution)
+                .replace(&format!("    {PLACEHOLDER}\n"), "");
+            fs::write(target, contents)?;
+        }
+    }
+    Ok(())
+}
+
+/// Append `entry` at the end of the one-entry-per-line list that follows the
+/// line `start` and finishes right before the line `end`
+fn append_line(text: &str, start: &str, end: &str, entry: &str) -> io::Result<String> {
+    let not_found = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("could not find the list starting with `{start}`"),
+        )
+    };
+    let lines: Vec<_> = text.lines().collect();
+    let start_idx = lines
+        .iter()
+        .position(|line| line.trim() == start)
+        .ok_or_else(not_found)?;
+    let end_idx = lines[start_idx + 1..]
+        .iter()
+        .position(|line| line.trim() == end)
+        .map(|i| i + start_idx + 1)
+        .unwrap_or(lines.len());
+    if lines[start_idx + 1..end_idx].contains(&entry) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("`{}` is already registered", entry.trim()),
+        ));
+    }
+
+    let mut result: Vec<_> = lines[..end_idx].to_vec();
+    result.push(entry);
+    result.extend_from_slice(&lines[end_idx..]);
+    Ok(result.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[package]
+name = "aoc-2022"
+
+[dependencies]
+day01 = { path = "day01" }
+
+[workspace]
+members = [
+    "day00",
+    "day01",
+]
+"#;
+
+    const DAYS: &str = "days! {\n    1 => day01::Day01,\n}\n";
+
+    #[test]
+    fn test_append_line() {
+        let manifest = append_line(MANIFEST, "members = [", "]", "    \"day02\",").unwrap();
+        assert!(manifest.ends_with("    \"day01\",\n    \"day02\",\n]\n"));
+
+        let manifest = append_line(&manifest, "[dependencies]", "", "day02 = {}").unwrap();
+        assert!(manifest.contains("day01 = { path = \"day01\" }\nday02 = {}\n\n[workspace]"));
+
+        let err = append_line(&manifest, "members = [", "]", "    \"day01\",").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("src").join("days.rs"), DAYS).unwrap();
+        let template = Path::new(env!("CARGO_MANIFEST_DIR")).join(TEMPLATE);
+        copy_template(&template, &root.join(TEMPLATE), TEMPLATE, "Day00").unwrap();
+
+        let dir = new_day(&root, 7).unwrap();
+        assert!(dir.join("input.txt").exists());
+        assert!(dir.join("sample.txt").exists());
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day07\""));
+        let lib = fs::read_to_string(dir.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day07"));
+        // The placeholder tests of the template fail in the new day
+        let part1 = fs::read_to_string(template.join("src").join("part1.rs")).unwrap();
+        assert!(part1.contains(&format!("    {PLACEHOLDER}\n")));
+        let part1 = fs::read_to_string(dir.join("src").join("part1.rs")).unwrap();
+        assert!(!part1.contains(PLACEHOLDER));
+        let days = fs::read_to_string(root.join("src").join("days.rs")).unwrap();
+        assert!(days.contains("    7 => day07::Day07,\n}"));
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("    \"day07\",\n]"));
+        assert!(manifest.contains("day07 = { path = \"day07\" }\n"));
+
+        // The day can only be created once
+        let err = new_day(&root, 7).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,271 @@
+use aoc_utils::{Part, Solution};
+use std::{
+    any::Any,
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+/// Outcome of one phase (parsing or a part) of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Done {
+        answer: String,
+        time: Duration,
+    },
+    MissingInput(String),
+    /// The input could not be parsed
+    Invalid(String),
+    Panicked(String),
+    /// Not run because an earlier phase failed
+    Skipped,
+}
+
+impl Cell {
+    fn label(&self) -> &str {
+        match self {
+            Cell::Done { answer, .. } if answer.contains('\n') => "(see below)",
+            Cell::Done { answer, .. } => answer,
+            Cell::MissingInput(_) => "missing input",
+            Cell::Invalid(_) => "invalid input",
+            Cell::Panicked(_) => "panicked",
+            Cell::Skipped => "-",
+        }
+    }
+
+    fn time(&self) -> String {
+        match self {
+            Cell::Done { time, .. } => format!("{time:.1?}"),
+            _ => "-".to_string(),
+        }
+    }
+
+    /// Whether the solution of the day failed in this phase
+    pub fn failed(&self) -> bool {
+        matches!(self, Cell::Invalid(_) | Cell::Panicked(_))
+    }
+
+    /// Why the solution of the day failed in this phase, if it did, with
+    /// the error on the lines below
+    pub fn failure(&self) -> Option<String> {
+        match self {
+            Cell::Invalid(e) | Cell::Panicked(e) => Some(format!("{}\n{e}", self.label())),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of parsing the input of a day and solving each part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub parse: Cell,
+    pub parts: [Cell; 2],
+}
+
+impl Row {
+    pub fn missing_input(day: u8, reason: String) -> Self {
+        Self {
+            day,
+            parse: Cell::MissingInput(reason),
+            parts: [Cell::Skipped, Cell::Skipped],
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.parse.failed() || self.parts.iter().any(Cell::failed)
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Run `f`, timing it and turning a panic into a failed cell
+fn timed(f: impl FnOnce() -> String) -> Cell {
+    let start = Instant::now();
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(answer) => Cell::Done {
+            answer,
+            time: start.elapsed(),
+        },
+        Err(payload) => Cell::Panicked(panic_message(payload)),
+    }
+}
+
+/// Parse the `input` of `day` and solve both parts, catching any failure
+pub fn run<S: Solution>(day: u8, input: &str) -> Row {
+    let start = Instant::now();
+    let parsed = match panic::catch_unwind(|| S::parse(input)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => {
+            return Row {
+                day,
+                parse: Cell::Invalid(e.to_string()),
+                parts: [Cell::Skipped, Cell::Skipped],
+            }
+        }
+        Err(payload) => {
+            return Row {
+                day,
+                parse: Cell::Panicked(panic_message(payload)),
+                parts: [Cell::Skipped, Cell::Skipped],
+            }
+        }
+    };
+    let parse = Cell::Done {
+        answer: String::new(),
+        time: start.elapsed(),
+    };
+    let parts = Part::ALL.map(|part| timed(|| S::solve(&parsed, part)));
+    Row { day, parse, parts }
+}
+
+/// Render the `rows` as a table of answers and timings, followed by the
+/// details of the failures and the answers that span several lines
+pub fn table(rows: &[Row]) -> String {
+    let width = |part: usize| {
+        rows.iter()
+            .map(|row| row.parts[part].label().chars().count())
+            .chain(["part N".len()])
+            .max()
+            .unwrap_or_default()
+    };
+    let (width1, width2) = (width(0), width(1));
+    let mut table = String::new();
+    writeln!(
+        table,
+        "{:>3}  {:>13}  {:<width1$}  {:>10}  {:<width2$}  {:>10}",
+        "day", "parse", "part 1", "time", "part 2", "time"
+    )
+    .unwrap();
+    let mut details = String::new();
+    for row in rows {
+        let parse = match &row.parse {
+            Cell::Done { .. } => row.parse.time(),
+            cell => cell.label().to_string(),
+        };
+        let [part1, part2] = &row.parts;
+        writeln!(
+            table,
+            "{:>3}  {:>13}  {:<width1$}  {:>10}  {:<width2$}  {:>10}",
+            row.day,
+            parse,
+            part1.label(),
+            part1.time(),
+            part2.label(),
+            part2.time(),
+        )
+        .unwrap();
+
+        match &row.parse {
+            Cell::MissingInput(detail) | Cell::Invalid(detail) | Cell::Panicked(detail) => {
+                writeln!(details, "Day {}: {detail}", row.day).unwrap();
+            }
+            _ => {}
+        }
+        for (part, cell) in Part::ALL.iter().zip(&row.parts) {
+            match cell {
+                Cell::Done { answer, .. } if answer.contains('\n') => {
+                    writeln!(details, "Day {} part {part}:\n{answer}", row.day).unwrap();
+                }
+                Cell::Panicked(detail) => {
+                    writeln!(details, "Day {} part {part}: {detail}", row.day).unwrap();
+                }
+                _ => {}
+            }
+        }
+    }
+    if !details.is_empty() {
+        table.push('\n');
+    }
+    table + &details
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::parse::{Line, ParseError};
+
+    /// Sums the numbers of the input, part 2 panics on odd sums
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            aoc_utils::parse::lines(input)
+                .map(|line: Line| line.number(line.text))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Self::Answer1 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> Self::Answer2 {
+            let sum = Self::part1(input);
+            assert!(sum.is_multiple_of(2), "odd sum");
+            sum
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let row = run::<Sum>(1, "1\n3\n");
+        assert!(!row.failed());
+        assert!(matches!(&row.parts[1], Cell::Done { answer, .. } if answer == "4"));
+
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let row = run::<Sum>(1, "1\n2\n");
+        panic::set_hook(hook);
+        assert!(row.failed());
+        assert!(matches!(&row.parts[0], Cell::Done { answer, .. } if answer == "3"));
+        assert_eq!(row.parts[1], Cell::Panicked("odd sum".to_string()));
+
+        let row = run::<Sum>(1, "1\nx\n");
+        assert!(matches!(row.parse, Cell::Invalid(_)));
+        assert_eq!(row.parts, [Cell::Skipped, Cell::Skipped]);
+    }
+
+    #[test]
+    fn test_table() {
+        let rows = vec![
+            Row {
+                day: 1,
+                parse: Cell::Done {
+                    answer: String::new(),
+                    time: Duration::from_micros(5),
+                },
+                parts: [
+                    Cell::Done {
+                        answer: "24000".to_string(),
+                        time: Duration::from_micros(10),
+                    },
+                    Cell::Panicked("oops".to_string()),
+                ],
+            },
+            Row::missing_input(2, "day02/input.txt: not found".to_string()),
+        ];
+        let table = table(&rows);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(
+            lines[1],
+            "  1          5.0µs  24000       10.0µs  panicked           -"
+        );
+        assert_eq!(
+            lines[2],
+            "  2  missing input  -                -  -                  -"
+        );
+        assert!(table.contains("\n\nDay 1 part 2: oops\nDay 2: day02/input.txt: not found\n"));
+    }
+}